@@ -1,12 +1,291 @@
 use eframe::egui;
-use crate::launcher;
-use crate::credentials::{self, Account};
-use crate::settings::{self, Settings};
+use std::sync::mpsc;
+use crate::launcher::{self, LaunchEvent};
+use crate::credentials::{self, Account, AccountStats, LaunchOutcome, QueueRank};
+use crate::lcu;
+use crate::decay;
+use crate::restrictions;
+use crate::match_history::{self, StoredMatch};
+use crate::notifications;
+use crate::client_watcher::{ClientWatcher, WatcherConfig, WatcherEvent};
+use crate::champ_select::{self, ChampSelectPreferences};
+use crate::presets::{self, LoadoutPreset, RunePreset};
+use std::collections::{HashMap, HashSet};
+use crate::settings::{self, AccountSort, Settings};
+use crate::riot_client;
+use crate::i18n::{self, t};
+use crate::trash::{self, TrashedAccount};
+use crate::theme::{self, Palette};
+use crate::settings_page::{self, Effect};
+use crate::widgets::toggle_row;
 
 #[derive(PartialEq)]
 pub enum View {
     Login,
+    Accounts,
     Settings,
+    ChampSelect,
+    Presets,
+    AccountDetails,
+    MatchHistory,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DetailsColumn {
+    Account,
+    Level,
+    BlueEssence,
+    OrangeEssence,
+    RiotPoints,
+    Champions,
+    Honor,
+    Loot,
+    Keys,
+    Logins,
+    LastUsed,
+    Updated,
+}
+
+impl DetailsColumn {
+    const ALL: [DetailsColumn; 12] = [
+        DetailsColumn::Account,
+        DetailsColumn::Level,
+        DetailsColumn::BlueEssence,
+        DetailsColumn::OrangeEssence,
+        DetailsColumn::RiotPoints,
+        DetailsColumn::Champions,
+        DetailsColumn::Honor,
+        DetailsColumn::Loot,
+        DetailsColumn::Keys,
+        DetailsColumn::Logins,
+        DetailsColumn::LastUsed,
+        DetailsColumn::Updated,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            DetailsColumn::Account => t!("details.account"),
+            DetailsColumn::Level => t!("details.level"),
+            DetailsColumn::BlueEssence => t!("details.blue_essence"),
+            DetailsColumn::OrangeEssence => t!("details.orange_essence"),
+            DetailsColumn::RiotPoints => t!("details.riot_points"),
+            DetailsColumn::Champions => t!("details.champions"),
+            DetailsColumn::Honor => t!("details.honor"),
+            DetailsColumn::Loot => t!("details.loot"),
+            DetailsColumn::Keys => t!("details.keys"),
+            DetailsColumn::Logins => t!("details.logins"),
+            DetailsColumn::LastUsed => t!("details.last_used"),
+            DetailsColumn::Updated => t!("details.updated"),
+        }
+    }
+
+    fn value(self, account: &Account) -> Option<u64> {
+        let wallet = account.wallet.as_ref();
+        match self {
+            DetailsColumn::Account => None,
+            DetailsColumn::Level => account.stats.as_ref().map(|stats| stats.summoner_level as u64),
+            DetailsColumn::BlueEssence => wallet.and_then(|w| w.blue_essence),
+            DetailsColumn::OrangeEssence => wallet.and_then(|w| w.orange_essence),
+            DetailsColumn::RiotPoints => wallet.and_then(|w| w.riot_points),
+            DetailsColumn::Champions => wallet.and_then(|w| w.champions_owned).map(u64::from),
+            DetailsColumn::Honor => wallet.and_then(|w| w.honor_level).map(u64::from),
+            DetailsColumn::Loot => wallet.and_then(|w| w.unopened_loot).map(u64::from),
+            DetailsColumn::Keys => wallet.and_then(|w| w.keys).map(u64::from),
+            DetailsColumn::Logins => Some(account.use_count as u64),
+            DetailsColumn::LastUsed => account.last_used_at,
+            DetailsColumn::Updated => wallet.map(|w| w.updated_at),
+        }
+    }
+}
+
+enum PickerEntry {
+    Header(&'static str),
+    Group { name: String, count: usize, collapsed: bool },
+    Account(usize),
+    // A shortcut in the "Recently used" section; the account is listed again below.
+    Recent(usize),
+}
+
+struct OrganizeDraft {
+    username: String,
+    group: String,
+    labels: String,
+    notes: String,
+    color: Option<[u8; 3]>,
+}
+
+// The inline editor of the account manager. `original_username` is None for a new account.
+struct AccountDraft {
+    original_username: Option<String>,
+    username: String,
+    password: String,
+    region: String,
+    in_game_name: String,
+    custom_tag: String,
+    language: String,
+    group: String,
+    auto_accept: bool,
+}
+
+impl AccountDraft {
+    fn new_account() -> Self {
+        Self {
+            original_username: None,
+            username: String::new(),
+            password: String::new(),
+            region: "EUNE".to_owned(),
+            in_game_name: String::new(),
+            custom_tag: String::new(),
+            language: String::new(),
+            group: String::new(),
+            auto_accept: false,
+        }
+    }
+
+    fn from_account(account: &Account) -> Self {
+        Self {
+            original_username: Some(account.username.clone()),
+            username: account.username.clone(),
+            password: account.password.clone(),
+            region: account.region.clone(),
+            in_game_name: account.in_game_name.clone(),
+            custom_tag: account.custom_tag.clone(),
+            language: account.language.clone(),
+            group: account.group.clone(),
+            auto_accept: account.auto_accept,
+        }
+    }
+}
+
+// A change to the saved accounts, with what is needed to revert it.
+enum AccountChange {
+    Added(String),
+    // Original positions, in ascending order.
+    Deleted(Vec<(usize, Account)>),
+    // The username after the edit and the edited fields as they were before.
+    Edited(Vec<(String, EditedFields)>),
+    Moved { from: usize, to: usize },
+    Restored(Box<TrashedAccount>),
+}
+
+impl AccountChange {
+    // Puts the accounts, and the trash where it was involved, back as they were.
+    fn revert(self, accounts: &mut Vec<Account>, trash: &mut Vec<TrashedAccount>) {
+        match self {
+            AccountChange::Added(username) => accounts.retain(|acc| acc.username != username),
+            AccountChange::Deleted(deleted) => {
+                for (idx, account) in deleted {
+                    // Skipped when the username was saved again since; the account stays in the trash.
+                    if accounts.iter().any(|acc| acc.username == account.username) {
+                        continue;
+                    }
+                    if let Some(pos) = trash.iter().rposition(|entry| entry.account.username == account.username) {
+                        trash.remove(pos);
+                    }
+                    let idx = idx.min(accounts.len());
+                    accounts.insert(idx, account);
+                }
+            }
+            AccountChange::Edited(edits) => {
+                for (username, before) in edits {
+                    if let Some(account) = accounts.iter_mut().find(|acc| acc.username == username) {
+                        before.restore(account);
+                    }
+                }
+            }
+            AccountChange::Moved { from, to } => {
+                if to < accounts.len() {
+                    let account = accounts.remove(to);
+                    accounts.insert(from.min(accounts.len()), account);
+                }
+            }
+            AccountChange::Restored(entry) => {
+                if let Some(pos) = accounts.iter().position(|acc| acc.username == entry.account.username) {
+                    accounts.remove(pos);
+                    trash.push(*entry);
+                }
+            }
+        }
+    }
+}
+
+// What the account forms can change. Undoing an edit puts back only these, so stats,
+// wallet, restrictions and usage recorded since the edit are kept.
+struct EditedFields {
+    username: String,
+    password: String,
+    region: String,
+    language: String,
+    in_game_name: String,
+    custom_tag: String,
+    group: String,
+    labels: Vec<String>,
+    notes: String,
+    color: Option<[u8; 3]>,
+    auto_accept: bool,
+}
+
+impl EditedFields {
+    fn of(account: &Account) -> Self {
+        Self {
+            username: account.username.clone(),
+            password: account.password.clone(),
+            region: account.region.clone(),
+            language: account.language.clone(),
+            in_game_name: account.in_game_name.clone(),
+            custom_tag: account.custom_tag.clone(),
+            group: account.group.clone(),
+            labels: account.labels.clone(),
+            notes: account.notes.clone(),
+            color: account.color,
+            auto_accept: account.auto_accept,
+        }
+    }
+
+    fn restore(self, account: &mut Account) {
+        account.username = self.username;
+        account.password = self.password;
+        account.region = self.region;
+        account.language = self.language;
+        account.in_game_name = self.in_game_name;
+        account.custom_tag = self.custom_tag;
+        account.group = self.group;
+        account.labels = self.labels;
+        account.notes = self.notes;
+        account.color = self.color;
+        account.auto_accept = self.auto_accept;
+    }
+}
+
+// Where an account dropped in front of row `target` ends up, or None when it stays put.
+fn drop_position(from: usize, target: usize, len: usize) -> Option<usize> {
+    if from >= len {
+        return None;
+    }
+    let to = if target > from { target - 1 } else { target };
+    let to = to.min(len - 1);
+    (to != from).then_some(to)
+}
+
+struct UndoEntry {
+    change: AccountChange,
+    description: String,
+    at: std::time::Instant,
+}
+
+const UNDO_LIMIT: usize = 20;
+const UNDO_TOAST_SECS: u64 = 8;
+
+struct RiotIdUpdate {
+    username: String,
+    in_game_name: String,
+    custom_tag: String,
+}
+
+// The current rune page, plus the summoner spells when imported during champion select.
+struct RuneImport {
+    page: lcu::RunePage,
+    spells: Option<(i64, i64)>,
 }
 
 pub struct RustyLeagueApp {
@@ -18,6 +297,8 @@ pub struct RustyLeagueApp {
     region: String,
     in_game_name: String,
     custom_tag: String,
+    language: String,
+    auto_accept: bool,
 
     saved_accounts: Vec<Account>,
     
@@ -27,26 +308,62 @@ pub struct RustyLeagueApp {
     show_password: bool,
 
     alert_message: Option<String>,
+    riot_install_search: riot_client::InstallSearch,
+    riot_install_check: riot_client::InstallCheck,
+    launch_events: Option<mpsc::Receiver<LaunchEvent>>,
+    launch_status: Option<String>,
+    launch_username: Option<String>,
+    pending_riot_id: Option<RiotIdUpdate>,
+    decay_notified: HashMap<String, u64>,
+    last_decay_check: Option<std::time::Instant>,
+    client_watcher: Option<ClientWatcher>,
+    connected_username: Option<String>,
+    champ_select_username: String,
+    champ_select_prefs: ChampSelectPreferences,
+    champ_select_texts: HashMap<String, (String, String)>,
+    preset_draft: LoadoutPreset,
+    preset_draft_index: Option<usize>,
+    rune_import: Option<mpsc::Receiver<Result<RuneImport, String>>>,
+    details_sort: DetailsColumn,
+    details_sort_descending: bool,
+    history_username: String,
+    history_matches: Vec<StoredMatch>,
+    history_champion_filter: String,
+    history_queue_filter: Option<i32>,
     dragged_account_idx: Option<usize>,
-    drag_offset: Option<egui::Vec2>,
+    account_search: String,
+    settings_search: String,
+    // The Riot Client path being edited; it only replaces the saved one once it is confirmed.
+    riot_path_draft: Option<String>,
+    // A setting is still being dragged or typed and has not been saved yet.
+    settings_unsaved: bool,
+    account_search_cursor: usize,
+    account_search_moved: bool,
+    organize_draft: Option<OrganizeDraft>,
+    account_draft: Option<AccountDraft>,
+    manager_selected: HashSet<String>,
+    manager_group_target: String,
+    undo_stack: Vec<UndoEntry>,
+    trash: Vec<TrashedAccount>,
+    show_trash: bool,
+    detecting_install: bool,
 }
 
 impl Default for RustyLeagueApp {
     fn default() -> Self {
         let accounts = credentials::load_accounts();
         let settings = settings::load_settings();
+        i18n::set_language(settings.ui_language);
         
-        let start_view = if settings.riot_client_path.is_empty() {
-             View::Settings
-        } else {
-             View::Login
-        };
+        // Without a saved path the first detected installation is used; Settings only opens
+        // when there is none.
+        let detecting_install = settings.riot_client_path.is_empty();
 
         if accounts.is_empty() {
              let _ = credentials::save_accounts(&[]);
         }
 
-        let (selected_display, sel_username, sel_password, sel_region, sel_ign, sel_tag) = 
+        let (selected_display, sel_username, sel_password, sel_region, sel_ign, sel_tag, sel_language, sel_auto_accept) = 
             if let Some(first) = accounts.first() {
                 (
                     format!("{}           {}", first.full_name(), first.region),
@@ -55,51 +372,139 @@ impl Default for RustyLeagueApp {
                     first.region.clone(),
                     first.in_game_name.clone(),
                     first.custom_tag.clone(),
+                    first.language.clone(),
+                    first.auto_accept,
                 )
             } else {
                 (
-                    "Select an account...".to_owned(),
+                    t!("picker.placeholder").to_owned(),
                     String::new(),
                     String::new(),
                     "EUNE".to_owned(),
                     String::new(),
                     String::new(),
+                    String::new(),
+                    false,
                 )
             };
 
         Self {
-            current_view: start_view,
+            current_view: View::Login,
             settings: settings,
             username: sel_username,
             password: sel_password,
             region: sel_region,
             in_game_name: sel_ign,
             custom_tag: sel_tag,
+            language: sel_language,
+            auto_accept: sel_auto_accept,
             saved_accounts: accounts,
             selected_account_display: selected_display,
             show_delete_confirmation: false,
             show_password: false,
             alert_message: None,
+            riot_install_search: riot_client::InstallSearch::default(),
+            riot_install_check: riot_client::InstallCheck::default(),
+            launch_events: None,
+            launch_status: None,
+            launch_username: None,
+            pending_riot_id: None,
+            decay_notified: HashMap::new(),
+            last_decay_check: None,
+            client_watcher: None,
+            connected_username: None,
+            champ_select_username: String::new(),
+            champ_select_prefs: ChampSelectPreferences::default(),
+            champ_select_texts: HashMap::new(),
+            preset_draft: LoadoutPreset::default(),
+            preset_draft_index: None,
+            rune_import: None,
+            details_sort: DetailsColumn::Account,
+            details_sort_descending: false,
+            history_username: String::new(),
+            history_matches: Vec::new(),
+            history_champion_filter: String::new(),
+            history_queue_filter: None,
             dragged_account_idx: None,
-            drag_offset: None,
+            account_search: String::new(),
+            settings_search: String::new(),
+            riot_path_draft: None,
+            settings_unsaved: false,
+            account_search_cursor: 0,
+            account_search_moved: false,
+            organize_draft: None,
+            account_draft: None,
+            manager_selected: HashSet::new(),
+            manager_group_target: String::new(),
+            undo_stack: Vec::new(),
+            trash: trash::load_trash(credentials::unix_now()),
+            show_trash: false,
+            detecting_install,
         }
     }
 }
 
 impl RustyLeagueApp {
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        Self::default()
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let app = Self::default();
+        theme::apply(&cc.egui_ctx, app.settings.theme_mode, app.settings.accent_color);
+        app
+    }
+
+    fn ui_scale(&self, ctx: &egui::Context) -> f32 {
+        self.settings.ui_scale.unwrap_or_else(|| ctx.native_pixels_per_point().unwrap_or(1.0))
+    }
+
+    // Viewport rects are in points, which the zoom factor turns back into logical pixels.
+    // The monitor's own scale factor is kept so startup can find it in physical pixels.
+    fn remember_window_geometry(&mut self, ctx: &egui::Context) {
+        let (outer, inner, maximized, minimized) = ctx.input(|i| {
+            let viewport = i.viewport();
+            (viewport.outer_rect, viewport.inner_rect, viewport.maximized, viewport.minimized)
+        });
+        if minimized == Some(true) {
+            return;
+        }
+
+        let zoom = ctx.zoom_factor();
+        let geometry = match (maximized == Some(true), self.settings.window, outer, inner) {
+            // The restored size is not reported while maximized, so keep the last one.
+            (true, Some(previous), _, _) => settings::WindowGeometry { maximized: true, ..previous },
+            (_, _, Some(outer), Some(inner)) => settings::WindowGeometry {
+                x: outer.min.x * zoom,
+                y: outer.min.y * zoom,
+                width: inner.width() * zoom,
+                height: inner.height() * zoom,
+                maximized: maximized == Some(true),
+                scale_factor: ctx.native_pixels_per_point().unwrap_or(1.0),
+            },
+            _ => return,
+        };
+        if self.settings.window != Some(geometry) {
+            self.settings.window = Some(geometry);
+            let _ = settings::save_settings(&self.settings);
+        }
     }
 }
 
 impl eframe::App for RustyLeagueApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        ctx.set_visuals(egui::Visuals::dark());
-        ctx.set_pixels_per_point(1.5);
+        let scale = self.ui_scale(ctx);
+        if (ctx.pixels_per_point() - scale).abs() > 0.001 {
+            ctx.set_pixels_per_point(scale);
+        }
+        if ctx.input(|i| i.viewport().close_requested()) {
+            self.remember_window_geometry(ctx);
+        }
+
+        self.poll_launch_events(ctx);
+        self.adopt_detected_install(ctx);
+        self.check_decay_notifications();
+        self.poll_client_watcher(ctx);
 
         let mut close_alert = false;
         if let Some(msg) = &self.alert_message {
-            egui::Window::new("Info")
+            egui::Window::new(t!("alert.title"))
                 .collapsible(false)
                 .resizable(false)
                 .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
@@ -110,7 +515,7 @@ impl eframe::App for RustyLeagueApp {
                         let available_width = ui.available_width();
                         let btn_width = 30.0; 
                         ui.add_space((available_width - btn_width) / 2.0);
-                        if ui.button("OK").clicked() {
+                        if ui.button(t!("common.ok")).clicked() {
                             close_alert = true;
                         }
                     });
@@ -120,625 +525,2291 @@ impl eframe::App for RustyLeagueApp {
             self.alert_message = None;
         }
 
+        self.render_riot_id_confirmation(ctx);
+        self.render_organize_window(ctx);
+        self.render_undo_toast(ctx);
+
         match self.current_view {
             View::Settings => self.render_settings_view(ctx),
             View::Login => self.render_login_view(ctx),
+            View::Accounts => self.render_accounts_view(ctx),
+            View::ChampSelect => self.render_champ_select_view(ctx),
+            View::Presets => self.render_presets_view(ctx),
+            View::AccountDetails => self.render_account_details_view(ctx),
+            View::MatchHistory => self.render_match_history_view(ctx),
         }
     }
 }
 
 impl RustyLeagueApp {
-    fn render_settings_view(&mut self, ctx: &egui::Context) {
-        egui::Area::new(egui::Id::new("settings_gear_area"))
-            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
-            .show(ctx, |ui| {
-                 if ui.add(egui::Button::new("⚙").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
-                     if let Err(e) = settings::save_settings(&self.settings) {
-                         self.alert_message = Some(format!("Error saving settings: {}", e));
-                     }
-                     self.current_view = View::Login;
-                 }
-            });
+    fn select_account(&mut self, idx: usize) {
+        if let Some(account) = self.saved_accounts.get(idx) {
+            self.selected_account_display = format!("{}           {}", account.full_name(), account.region);
+            self.username = account.username.clone();
+            self.password = account.password.clone();
+            self.region = account.region.clone();
+            self.in_game_name = account.in_game_name.clone();
+            self.custom_tag = account.custom_tag.clone();
+            self.language = account.language.clone();
+            self.auto_accept = account.auto_accept;
+        }
+    }
 
-        egui::CentralPanel::default().show(ctx, |ui| {
-            ui.vertical_centered(|ui| {
-                ui.add_space(20.0);
-                ui.heading("Rusty Settings");
-                ui.add_space(30.0);
-                
-                ui.label("Path to Riot Client Services (EXE):");
-                ui.add_space(5.0);
-                
-                let text_edit_width = 300.0;
-                let button_width = 30.0; 
-                let spacing = ui.spacing().item_spacing.x;
-                let total_width = text_edit_width + spacing + button_width;
-                let margin = (ui.available_width() - total_width) / 2.0;
+    fn selected_account_index(&self) -> Option<usize> {
+        self.saved_accounts.iter().position(|acc| {
+            format!("{}           {}", acc.full_name(), acc.region) == self.selected_account_display
+        })
+    }
 
-                ui.horizontal(|ui| {
-                    ui.add_space(margin.max(0.0));
-                    ui.add(egui::TextEdit::singleline(&mut self.settings.riot_client_path).desired_width(text_edit_width));
-                    if ui.button("📂").clicked() {
-                        if let Some(path) = rfd::FileDialog::new()
-                            .add_filter("Executables", &["exe"])
-                            .pick_file() 
-                        {
-                            self.settings.riot_client_path = path.to_string_lossy().into_owned();
-                        }
-                    }
-                });
+    // Search field and group chips at the top of both account pickers. Up/Down move the
+    // keyboard cursor through the listed accounts and Enter picks the one under it.
+    fn account_search_box(&mut self, ui: &mut egui::Ui) -> (Vec<PickerEntry>, Option<usize>) {
+        let (down, up, enter) = ui.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            )
+        });
 
-                ui.add_space(25.0);
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.account_search)
+                .hint_text(t!("picker.search_hint"))
+                .desired_width(f32::INFINITY),
+        );
+        if !response.has_focus() {
+            response.request_focus();
+        }
+        if response.changed() {
+            self.account_search_cursor = 0;
+        }
 
-                ui.horizontal(|ui| {
-                    let switch_width = 40.0;
-                    let switch_height = 20.0;
-                    let label_text = "Minimalist Mode";
-                    let label_galley = ui.painter().layout_no_wrap(
-                        label_text.to_string(),
-                        egui::TextStyle::Body.resolve(ui.style()),
-                        ui.visuals().text_color(),
-                    );
-                    let label_width = label_galley.size().x;
-                    let total_w = label_width + 10.0 + switch_width;
-                    let avail = ui.available_width();
-                    ui.add_space(((avail - total_w) / 2.0).max(0.0));
+        let groups = credentials::group_names(&self.saved_accounts);
+        let mut changed = false;
+        ui.horizontal_wrapped(|ui| {
+            let sort_text = format!("⇅ {}", self.settings.account_sort.label());
+            if ui.small_button(sort_text).on_hover_text(t!("picker.change_order")).clicked() {
+                self.settings.account_sort = self.settings.account_sort.next();
+                changed = true;
+            }
+            if !groups.is_empty() {
+                ui.separator();
+                changed |= ui.toggle_value(&mut self.settings.picker_grouped, t!("picker.group")).changed();
+                ui.separator();
+                changed |= ui
+                    .selectable_value(&mut self.settings.picker_group_filter, None, t!("picker.all"))
+                    .changed();
+                for group in &groups {
+                    changed |= ui
+                        .selectable_value(&mut self.settings.picker_group_filter, Some(group.clone()), group)
+                        .changed();
+                }
+            }
+        });
+        if changed {
+            self.account_search_cursor = 0;
+            let _ = settings::save_settings(&self.settings);
+        }
+        ui.add_space(4.0);
+
+        let group_filter = self.settings.picker_group_filter.as_deref().filter(|group| groups.iter().any(|g| g == group));
+        let mut matching: Vec<usize> = self
+            .saved_accounts
+            .iter()
+            .enumerate()
+            .filter(|(_, account)| group_filter.is_none_or(|group| account.group_name() == group))
+            .filter(|(_, account)| account_matches_search(account, &self.account_search))
+            .map(|(idx, _)| idx)
+            .collect();
+        credentials::sort_indices(&self.saved_accounts, &mut matching, self.settings.account_sort);
+
+        let mut entries = Vec::new();
+        if self.settings.picker_grouped && group_filter.is_none() && !groups.is_empty() {
+            // Stable sort keeps the manual order inside each group; ungrouped accounts go last.
+            matching.sort_by_key(|&idx| {
+                let group = self.saved_accounts[idx].group_name();
+                (group.is_empty(), group.to_lowercase())
+            });
+            let mut current: Option<&str> = None;
+            for &idx in &matching {
+                let group = self.saved_accounts[idx].group_name();
+                if current != Some(group) {
+                    current = Some(group);
+                    let count = matching.iter().filter(|&&i| self.saved_accounts[i].group_name() == group).count();
+                    entries.push(PickerEntry::Group {
+                        name: group.to_owned(),
+                        count,
+                        collapsed: self.settings.collapsed_groups.iter().any(|g| g == group),
+                    });
+                }
+                let collapsed = self.settings.collapsed_groups.iter().any(|g| g == group);
+                // A search always shows its results, even inside collapsed groups.
+                if !collapsed || !self.account_search.trim().is_empty() {
+                    entries.push(PickerEntry::Account(idx));
+                }
+            }
+        } else {
+            entries.extend(matching.into_iter().map(PickerEntry::Account));
+        }
 
-                    ui.label(label_text);
-                    ui.add_space(10.0);
+        let show_recent = self.settings.show_recent_accounts
+            && self.settings.account_sort != AccountSort::LastUsed
+            && self.account_search.trim().is_empty()
+            && group_filter.is_none();
+        if show_recent {
+            const RECENT_COUNT: usize = 3;
+            let mut recent: Vec<usize> = (0..self.saved_accounts.len())
+                .filter(|&idx| self.saved_accounts[idx].last_used_at.is_some())
+                .collect();
+            credentials::sort_indices(&self.saved_accounts, &mut recent, AccountSort::LastUsed);
+            recent.truncate(RECENT_COUNT);
+
+            // Only worth it when the list is longer than the shortcuts.
+            if !recent.is_empty() && self.saved_accounts.len() > RECENT_COUNT {
+                let mut with_recent = vec![PickerEntry::Header(t!("picker.recently_used"))];
+                with_recent.extend(recent.into_iter().map(PickerEntry::Recent));
+                if !matches!(entries.first(), Some(PickerEntry::Group { .. })) {
+                    with_recent.push(PickerEntry::Header(t!("picker.all_accounts")));
+                }
+                with_recent.append(&mut entries);
+                entries = with_recent;
+            }
+        }
 
-                    let desired_size = egui::vec2(switch_width, switch_height);
-                    let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
-                    if response.clicked() {
-                        self.settings.minimalist_mode = !self.settings.minimalist_mode;
-                        let _ = settings::save_settings(&self.settings);
-                    }
+        let listed: Vec<usize> = entries
+            .iter()
+            .filter_map(|entry| match entry {
+                PickerEntry::Account(idx) | PickerEntry::Recent(idx) => Some(*idx),
+                PickerEntry::Header(_) | PickerEntry::Group { .. } => None,
+            })
+            .collect();
+
+        let last = listed.len().saturating_sub(1);
+        self.account_search_moved = down || up;
+        if down {
+            self.account_search_cursor += 1;
+        }
+        if up {
+            self.account_search_cursor = self.account_search_cursor.saturating_sub(1);
+        }
+        self.account_search_cursor = self.account_search_cursor.min(last);
 
-                    let how_on = ui.ctx().animate_bool_with_time(
-                        response.id,
-                        self.settings.minimalist_mode,
-                        0.2,
-                    );
+        if entries.is_empty() {
+            ui.label(egui::RichText::new(t!("picker.no_matches")).weak());
+        }
 
-                    let corner_radius = switch_height / 2.0;
-                    let bg_color = egui::Color32::from_rgb(
-                        (60.0 + (0.0 - 60.0) * how_on) as u8,
-                        (60.0 + (180.0 - 60.0) * how_on) as u8,
-                        (60.0 + (80.0 - 60.0) * how_on) as u8,
-                    );
-                    ui.painter().rect_filled(rect, corner_radius, bg_color);
-
-                    let circle_radius = (switch_height / 2.0) - 2.0;
-                    let circle_x = rect.left() + circle_radius + 2.0
-                        + how_on * (switch_width - switch_height);
-                    let circle_center = egui::pos2(circle_x, rect.center().y);
-                    ui.painter().circle_filled(
-                        circle_center,
-                        circle_radius,
-                        egui::Color32::WHITE,
-                    );
-                });
+        let chosen = if enter { listed.get(self.account_search_cursor).copied() } else { None };
+        (entries, chosen)
+    }
 
-                ui.add_space(15.0);
+    fn toggle_group_collapsed(&mut self, group: &str) {
+        if let Some(pos) = self.settings.collapsed_groups.iter().position(|g| g == group) {
+            self.settings.collapsed_groups.remove(pos);
+        } else {
+            self.settings.collapsed_groups.push(group.to_owned());
+        }
+        let _ = settings::save_settings(&self.settings);
+    }
 
-                ui.horizontal(|ui| {
-                    let switch_width = 40.0;
-                    let switch_height = 20.0;
-                    let label_text = "Start with Windows";
-                    let label_galley = ui.painter().layout_no_wrap(
-                        label_text.to_string(),
-                        egui::TextStyle::Body.resolve(ui.style()),
-                        ui.visuals().text_color(),
-                    );
-                    let label_width = label_galley.size().x;
-                    let total_w = label_width + 10.0 + switch_width;
-                    let avail = ui.available_width();
-                    ui.add_space(((avail - total_w) / 2.0).max(0.0));
+    fn reset_account_search(&mut self) {
+        self.account_search.clear();
+        self.account_search_cursor = 0;
+        self.account_search_moved = false;
+    }
 
-                    ui.label(label_text);
-                    ui.add_space(10.0);
+    // The account combo shared by the launcher and the minimalist view.
+    fn render_account_picker(&mut self, ui: &mut egui::Ui, id_salt: &str, width: f32) {
+        let (display_name, display_region) = if self.selected_account_index().is_some() {
+            let parts: Vec<&str> = self.selected_account_display.split("           ").collect();
+            let name = parts.first().map(|s| s.to_string()).unwrap_or_else(|| self.selected_account_display.clone());
+            let region = parts.get(1).map(|s| s.to_string());
+            (name, region)
+        } else {
+            (t!("picker.placeholder").to_owned(), None)
+        };
 
-                    let desired_size = egui::vec2(switch_width, switch_height);
-                    let (rect, response) = ui.allocate_exact_size(desired_size, egui::Sense::click());
-                    if response.clicked() {
-                        self.settings.start_with_windows = !self.settings.start_with_windows;
-                        let _ = settings::save_settings(&self.settings);
-                        if let Err(e) = settings::set_autostart(self.settings.start_with_windows) {
-                            self.alert_message = Some(format!("Autostart error: {}", e));
+        let combo_response = egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(&display_name)
+            .width(width)
+            .height(250.0)
+            .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside)
+            .show_ui(ui, |ui| {
+                let (entries, mut selected_idx) = self.account_search_box(ui);
+                let mut toggled_group = None;
+                let mut position = 0;
+                for entry in &entries {
+                    let idx = match entry {
+                        PickerEntry::Header(title) => {
+                            section_header(ui, title);
+                            continue;
                         }
-                    }
+                        PickerEntry::Group { name, count, collapsed } => {
+                            if group_header(ui, name, *count, *collapsed) {
+                                toggled_group = Some(name.clone());
+                            }
+                            continue;
+                        }
+                        PickerEntry::Account(idx) | PickerEntry::Recent(idx) => *idx,
+                    };
+                    let is_cursor = position == self.account_search_cursor;
+                    position += 1;
+                    let account = &self.saved_accounts[idx];
+                    let label = format!("{}           {}", account.full_name(), account.region);
+                    let is_selected = self.selected_account_display == label;
 
-                    let how_on = ui.ctx().animate_bool_with_time(
-                        response.id,
-                        self.settings.start_with_windows,
-                        0.2,
-                    );
+                    let row_height = account_row_height(ui, account);
 
-                    let corner_radius = switch_height / 2.0;
-                    let bg_color = egui::Color32::from_rgb(
-                        (60.0 + (0.0 - 60.0) * how_on) as u8,
-                        (60.0 + (180.0 - 60.0) * how_on) as u8,
-                        (60.0 + (80.0 - 60.0) * how_on) as u8,
+                    let (rect, response) = ui.allocate_exact_size(
+                        egui::vec2(ui.available_width(), row_height),
+                        egui::Sense::click()
                     );
-                    ui.painter().rect_filled(rect, corner_radius, bg_color);
-
-                    let circle_radius = (switch_height / 2.0) - 2.0;
-                    let circle_x = rect.left() + circle_radius + 2.0
-                        + how_on * (switch_width - switch_height);
-                    let circle_center = egui::pos2(circle_x, rect.center().y);
-                    ui.painter().circle_filled(
-                        circle_center,
-                        circle_radius,
-                        egui::Color32::WHITE,
-                    );
-                });
+                    if is_cursor && self.account_search_moved {
+                        response.scroll_to_me(None);
+                    }
 
-                ui.add_space(25.0);
+                    if response.clicked() {
+                        selected_idx = Some(idx);
+                    }
 
-                if ui.button("Confirm Settings").clicked() {
-                    if self.settings.riot_client_path.is_empty() {
-                         self.alert_message = Some("Path cannot be empty!".into());
-                    } else if !std::path::Path::new(&self.settings.riot_client_path).exists() {
-                         self.alert_message = Some("File does not exist!".into());
-                    } else {
-                        if let Err(e) = settings::save_settings(&self.settings) {
-                            self.alert_message = Some(format!("Error saving settings: {}", e));
-                        } else {
-                            self.current_view = View::Login;
-                        }
+                    let visuals = ui.style().interact_selectable(&response, is_selected);
+                    if is_selected || is_cursor || response.hovered() || response.has_focus() {
+                        ui.painter().rect(
+                            rect,
+                            visuals.corner_radius,
+                            visuals.bg_fill,
+                            visuals.bg_stroke,
+                            egui::StrokeKind::Outside,
+                        );
                     }
+
+                    let text_color = visuals.text_color();
+                    paint_account_row(ui, ui.painter(), rect, account, text_color, self.settings.decay_warning_days, &self.account_search);
                 }
+                if let Some(group) = toggled_group {
+                    self.toggle_group_collapsed(&group);
+                }
+                if selected_idx.is_some() {
+                    ui.close();
+                }
+                selected_idx
             });
+
+        match combo_response.inner {
+            Some(Some(idx)) => self.select_account(idx),
+            Some(None) => {}
+            None => self.reset_account_search(),
+        }
+
+        if let Some(region) = &display_region {
+            let rect = combo_response.response.rect;
+            let font_id = egui::TextStyle::Body.resolve(ui.style());
+            let visuals = ui.style().interact_selectable(&combo_response.response, false);
+            let text_color = visuals.text_color();
+            ui.painter().text(
+                rect.right_center() - egui::vec2(25.0, 0.0),
+                egui::Align2::RIGHT_CENTER,
+                region,
+                font_id,
+                text_color,
+            );
+        }
+    }
+
+    fn start_login(&mut self) {
+        if self.username.is_empty() {
+            self.alert_message = Some(t!("error.choose_account").to_owned());
+            return;
+        }
+
+        let options = launcher::LaunchOptions {
+            riot_path: self.settings.riot_client_path.clone(),
+            timing: self.settings.login_timing(),
+            region: self.region.clone(),
+            locale: self.language.clone(),
+            restore_client_settings: self.settings.restore_client_locale,
+            auto_launch_league: self.settings.auto_launch_league,
+        };
+
+        self.launch_events = Some(launcher::launch_and_login(self.username.clone(), self.password.clone(), options));
+        self.launch_username = Some(self.username.clone());
+        self.launch_status = Some(t!("status.starting").to_owned());
+        self.update_launched_account(|account| {
+            account.last_used_at = Some(credentials::unix_now());
+            account.use_count += 1;
         });
     }
 
-    fn render_login_view(&mut self, ctx: &egui::Context) {
-        if self.settings.minimalist_mode {
-            self.render_minimalist_view(ctx);
+    fn adopt_detected_install(&mut self, ctx: &egui::Context) {
+        if !self.detecting_install {
             return;
         }
+        let Some(found) = self.riot_install_search.poll() else {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+            return;
+        };
+        self.detecting_install = false;
 
-        if self.show_delete_confirmation {
-            egui::Window::new("Potwierdzenie")
-                .collapsible(false)
-                .resizable(false)
-                .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-                .show(ctx, |ui| {
-                    ui.label("Czy na pewno chcesz usunąć wybrane konto?");
-                    ui.add_space(10.0);
-                    ui.horizontal(|ui| {
-                        if ui.button("Tak").clicked() {
-                            if let Some(index) = self.saved_accounts.iter().position(|acc| {
-                                let label = format!("{}           {}", acc.full_name(), acc.region);
-                                label == self.selected_account_display
-                            }) {
-                                self.saved_accounts.remove(index);
-                                let _ = credentials::save_accounts(&self.saved_accounts);
-                                self.selected_account_display = "Select an account...".to_owned();
-                                self.username.clear();
-                                self.password.clear();
-                                self.in_game_name.clear();
-                                self.custom_tag.clear();
-                            }
-                            self.show_delete_confirmation = false;
+        // A path set in Settings during the search wins.
+        if !self.settings.riot_client_path.is_empty() {
+            return;
+        }
+        match found.first() {
+            Some(path) => {
+                self.settings.riot_client_path = path.clone();
+                if let Err(e) = settings::save_settings(&self.settings) {
+                    self.alert_message = Some(t!("error.save_settings", error = e));
+                }
+            }
+            None => self.current_view = View::Settings,
+        }
+    }
+
+    fn poll_launch_events(&mut self, ctx: &egui::Context) {
+        let Some(events) = &self.launch_events else {
+            return;
+        };
+
+        let mut received = Vec::new();
+        let mut finished = false;
+        loop {
+            match events.try_recv() {
+                Ok(event) => received.push(event),
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                }
+            }
+        }
+
+        for event in received {
+            match event {
+                LaunchEvent::LaunchFailed(error) => {
+                    self.riot_install_search.restart();
+                    self.alert_message = Some(t!("error.generic", error = error));
+                    self.launch_status = None;
+                    self.update_launched_account(|account| account.last_outcome = Some(LaunchOutcome::Failed));
+                }
+                LaunchEvent::ClientSettingsNotApplied(error) => {
+                    self.alert_message = Some(t!("error.client_settings_apply", error = error));
+                }
+                LaunchEvent::ClientSettingsNotRestored(error) => {
+                    self.alert_message = Some(t!("error.client_settings_restore", error = error));
+                }
+                LaunchEvent::LoginScreenFound(elapsed) => {
+                    self.settings.record_login_screen_time(elapsed);
+                    let _ = settings::save_login_screen_samples(&self.settings.login_screen_samples_ms);
+                    self.launch_status = Some(t!("status.login_screen").to_owned());
+                }
+                LaunchEvent::LoginScreenTimedOut(waited) => {
+                    self.settings.record_login_screen_time(waited);
+                    let _ = settings::save_login_screen_samples(&self.settings.login_screen_samples_ms);
+                    self.launch_status = Some(t!("status.login_screen_timeout").to_owned());
+                }
+                LaunchEvent::CredentialsSubmitted => {
+                    let status = if self.settings.auto_launch_league {
+                        t!("status.submitted_launching")
+                    } else {
+                        t!("status.submitted")
+                    };
+                    self.launch_status = Some(status.to_owned());
+                }
+                LaunchEvent::LeagueClientStarted => {
+                    self.launch_status = Some(t!("status.league_started").to_owned());
+                }
+                LaunchEvent::LeagueClientNotStarted => {
+                    self.launch_status = Some(t!("status.league_not_started").to_owned());
+                    self.update_launched_account(|account| account.last_outcome = Some(LaunchOutcome::LeagueNotStarted));
+                }
+                LaunchEvent::SummonerLoaded(summoner) => {
+                    self.launch_status = Some(t!("status.signed_in", riot_id = format!("{}#{}", summoner.game_name, summoner.tag_line)));
+                    self.handle_detected_riot_id(&summoner.game_name, &summoner.tag_line);
+                    self.update_launched_account(|account| {
+                        account.last_outcome = Some(LaunchOutcome::SignedIn);
+                        let stats = account.stats.get_or_insert_with(AccountStats::default);
+                        stats.puuid = summoner.puuid.clone();
+                        stats.summoner_level = summoner.summoner_level;
+                        stats.updated_at = credentials::unix_now();
+                    });
+                }
+                LaunchEvent::MatchesLoaded(matches) => {
+                    if let Some(username) = self.launch_username.clone() {
+                        self.store_matches(&username, &matches);
+                    }
+                }
+                LaunchEvent::WalletLoaded(snapshot) => {
+                    self.update_launched_account(|account| account.wallet = Some(snapshot));
+                }
+                LaunchEvent::RestrictionsLoaded(found) => {
+                    let now = credentials::unix_now();
+                    let blocking = restrictions::most_severe(&found, now).filter(|r| r.is_blocking());
+                    if let Some(restriction) = blocking {
+                        self.launch_status = Some(t!("status.restricted", restriction = restriction.badge()));
+                    }
+                    let restricted = blocking.is_some();
+                    self.update_launched_account(|account| {
+                        if restricted {
+                            account.last_outcome = Some(LaunchOutcome::Restricted);
                         }
-                        if ui.button("Nie").clicked() {
-                            self.show_delete_confirmation = false;
+                        account.restrictions = found;
+                    });
+                }
+                LaunchEvent::RankedLoaded { ranked, last_solo_game_at } => {
+                    self.update_launched_account(|account| {
+                        let now = credentials::unix_now();
+                        let stats = account.stats.get_or_insert_with(AccountStats::default);
+                        let previous_solo = stats.solo_duo.take();
+                        stats.solo_duo = queue_rank(&ranked, credentials::SOLO_DUO_QUEUE);
+                        stats.flex = queue_rank(&ranked, credentials::FLEX_QUEUE);
+                        if last_solo_game_at.is_some() {
+                            stats.last_solo_game_at = last_solo_game_at;
                         }
+                        decay::update_bank(stats, previous_solo.as_ref(), now);
+                        stats.updated_at = now;
                     });
-                });
+                }
+            }
         }
 
-        egui::Area::new(egui::Id::new("settings_area"))
-            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
-            .show(ctx, |ui| {
-                 if ui.add(egui::Button::new("⚙").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
-                     self.current_view = View::Settings;
-                 }
-            });
-
-        egui::CentralPanel::default().show(ctx, |ui| {
-            egui::ScrollArea::vertical().show(ui, |ui| {
-                
-                ui.vertical_centered(|ui| {
-                    ui.add_space(20.0);
-                    ui.heading("Rusty League Login");
-                    ui.add_space(30.0);
-                });
-
-                let label_col_width = 115.0; 
-                let field_width = 250.0;     
-                let grid_spacing = [15.0, 15.0];
+        if finished {
+            self.launch_events = None;
+        } else {
+            ctx.request_repaint_after(std::time::Duration::from_millis(250));
+        }
+    }
 
-                let estimated_grid_width = label_col_width + (field_width + 8.0) + grid_spacing[0];
-                let margin_left = (ui.available_width() - estimated_grid_width) / 2.0;
-                let final_margin = if margin_left > 0.0 { margin_left } else { 10.0 };
+    fn poll_client_watcher(&mut self, ctx: &egui::Context) {
+        // The watcher polls the client and looks for its install folder with PowerShell, so it only
+        // runs while a feature depends on it.
+        let needed = self.watcher_needed();
+        if needed && self.client_watcher.is_none() {
+            self.client_watcher = Some(ClientWatcher::spawn());
+        } else if !needed && self.client_watcher.is_some() {
+            self.client_watcher = None;
+            self.connected_username = None;
+        }
+        let Some(watcher) = &self.client_watcher else {
+            return;
+        };
 
-                ui.horizontal(|ui| {
-                    ui.add_space(final_margin);
-                    
-                    egui::Grid::new("login_form_grid")
-                        .num_columns(2)
-                        .min_col_width(label_col_width) 
-                        .spacing(grid_spacing) 
-                        .striped(false)
-                        .show(ui, |ui| {
-                            
-                            ui.label("Username:");
-                            ui.add(egui::TextEdit::singleline(&mut self.username).desired_width(field_width));
-                            ui.end_row();
+        let mut received = Vec::new();
+        while let Some(event) = watcher.try_recv() {
+            received.push(event);
+        }
 
-                            ui.label("Password:");
-                            let password_response = ui.add(egui::TextEdit::singleline(&mut self.password).password(!self.show_password).desired_width(field_width));
-                            
-                            let eye_icon = if self.show_password { "🚫" } else { "👁" };
-                            let password_rect = password_response.rect;
-
-                            ui.scope_builder(egui::UiBuilder::new().max_rect(password_rect), |ui| {
-                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                    ui.add_space(8.0); 
-                                    if ui.add(egui::Button::new(eye_icon).frame(false).small()).on_hover_text("Show/Hide password").clicked() {
-                                        self.show_password = !self.show_password;
+        for event in received {
+            match event {
+                WatcherEvent::Connected(summoner) => {
+                    self.connected_username = self
+                        .account_for_summoner(&summoner)
+                        .map(|idx| self.saved_accounts[idx].username.clone());
+                    self.sync_watcher_config();
+                }
+                WatcherEvent::Disconnected => {
+                    self.connected_username = None;
+                    self.sync_watcher_config();
+                }
+                WatcherEvent::ReadyCheckAccepted => {
+                    self.launch_status = Some(t!("status.ready_check").to_owned());
+                    if let Err(e) = notifications::show(t!("notify.match_found"), t!("notify.match_found_body")) {
+                        self.alert_message = Some(t!("error.notification", error = e));
+                    }
+                }
+                WatcherEvent::ChampSelect(message) => {
+                    self.launch_status = Some(message);
+                }
+                WatcherEvent::MatchesLoaded(matches) => {
+                    if let Some(username) = self.connected_username.clone() {
+                        self.store_matches(&username, &matches);
+                    }
+                }
+            }
+        }
+
+        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+    }
+
+    fn watcher_needed(&self) -> bool {
+        self.settings.record_every_game
+            || !self.settings.presets.is_empty()
+            || self.saved_accounts.iter().any(|acc| acc.auto_accept || acc.champ_select.enabled)
+    }
+
+    fn account_for_summoner(&self, summoner: &lcu::Summoner) -> Option<usize> {
+        let by_puuid = self.saved_accounts.iter().position(|acc| {
+            acc.stats.as_ref().is_some_and(|stats| !stats.puuid.is_empty() && stats.puuid == summoner.puuid)
+        });
+        let by_riot_id = || {
+            self.saved_accounts.iter().position(|acc| {
+                acc.full_name().eq_ignore_ascii_case(&format!("{}#{}", summoner.game_name, summoner.tag_line))
+            })
+        };
+        // The launched account is only a guess while its launch is still running; later the
+        // user may have signed in with another account by hand.
+        let by_launch = || {
+            self.launch_events.as_ref()?;
+            let username = self.launch_username.as_ref()?;
+            self.saved_accounts.iter().position(|acc| &acc.username == username)
+        };
+
+        by_puuid.or_else(by_riot_id).or_else(by_launch)
+    }
+
+    fn sync_watcher_config(&self) {
+        let Some(watcher) = &self.client_watcher else {
+            return;
+        };
+
+        let account = self
+            .connected_username
+            .as_ref()
+            .and_then(|username| self.saved_accounts.iter().find(|acc| &acc.username == username));
+
+        watcher.set_config(WatcherConfig {
+            auto_accept: account.is_some_and(|acc| acc.auto_accept),
+            champ_select: account
+                .filter(|acc| acc.champ_select.enabled)
+                .map(|acc| acc.champ_select.clone()),
+            presets: self.settings.presets.clone(),
+            accept_delay: std::time::Duration::from_secs_f32(self.settings.auto_accept_delay_secs.max(0.0)),
+        });
+    }
+
+    fn check_decay_notifications(&mut self) {
+        const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+        const RENOTIFY_AFTER: u64 = 24 * 60 * 60;
+
+        if !self.settings.decay_notifications {
+            return;
+        }
+        if self.last_decay_check.is_some_and(|checked| checked.elapsed() < CHECK_INTERVAL) {
+            return;
+        }
+        self.last_decay_check = Some(std::time::Instant::now());
+
+        let now = credentials::unix_now();
+        for account in &self.saved_accounts {
+            let Some(status) = account.stats.as_ref().and_then(|stats| decay::status(stats, now)) else {
+                continue;
+            };
+            if !status.is_at_risk(self.settings.decay_warning_days) {
+                continue;
+            }
+            if self
+                .decay_notified
+                .get(&account.username)
+                .is_some_and(|notified| now.saturating_sub(*notified) < RENOTIFY_AFTER)
+            {
+                continue;
+            }
+
+            if let Err(e) = notifications::show(
+                t!("notify.decay"),
+                &format!("{} ({}): {}", account.full_name(), account.region, status.label()),
+            ) {
+                self.alert_message = Some(t!("error.notification", error = e));
+            }
+            self.decay_notified.insert(account.username.clone(), now);
+        }
+    }
+
+    fn update_launched_account(&mut self, update: impl FnOnce(&mut Account)) {
+        let Some(username) = &self.launch_username else {
+            return;
+        };
+        let Some(account) = self.saved_accounts.iter_mut().find(|acc| &acc.username == username) else {
+            return;
+        };
+
+        update(account);
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+    }
+
+    fn handle_detected_riot_id(&mut self, game_name: &str, tag_line: &str) {
+        let Some(username) = self.launch_username.clone() else {
+            return;
+        };
+        let Some(account) = self.saved_accounts.iter().find(|acc| acc.username == username) else {
+            return;
+        };
+        if game_name.is_empty() {
+            return;
+        }
+
+        // A tag equal to the region is stored as an empty custom tag, like the form does.
+        let custom_tag = if tag_line.eq_ignore_ascii_case(&account.region) {
+            String::new()
+        } else {
+            tag_line.to_owned()
+        };
+
+        if account.in_game_name == game_name && account.custom_tag.eq_ignore_ascii_case(&custom_tag) {
+            return;
+        }
+
+        let update = RiotIdUpdate {
+            username,
+            in_game_name: game_name.to_owned(),
+            custom_tag,
+        };
+
+        if account.in_game_name.trim().is_empty() {
+            self.apply_riot_id(update);
+        } else {
+            self.pending_riot_id = Some(update);
+        }
+    }
+
+    fn apply_riot_id(&mut self, update: RiotIdUpdate) {
+        let Some(idx) = self.saved_accounts.iter().position(|acc| acc.username == update.username) else {
+            return;
+        };
+
+        let was_selected = {
+            let account = &self.saved_accounts[idx];
+            format!("{}           {}", account.full_name(), account.region) == self.selected_account_display
+        };
+
+        let description = t!("undo.riot_id", name = self.saved_accounts[idx].full_name());
+        let before = EditedFields::of(&self.saved_accounts[idx]);
+        let account = &mut self.saved_accounts[idx];
+        account.in_game_name = update.in_game_name;
+        account.custom_tag = update.custom_tag;
+        self.record_change(AccountChange::Edited(vec![(update.username, before)]), description);
+
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+        if was_selected {
+            self.select_account(idx);
+        }
+    }
+
+    fn open_organize_window(&mut self) {
+        let Some(idx) = self.selected_account_index() else {
+            self.alert_message = Some(t!("error.choose_account").to_owned());
+            return;
+        };
+        let account = &self.saved_accounts[idx];
+        self.organize_draft = Some(OrganizeDraft {
+            username: account.username.clone(),
+            group: account.group.clone(),
+            labels: account.labels.join(", "),
+            notes: account.notes.clone(),
+            color: account.color,
+        });
+    }
+
+    fn render_organize_window(&mut self, ctx: &egui::Context) {
+        let groups = credentials::group_names(&self.saved_accounts);
+        let Some(draft) = &mut self.organize_draft else {
+            return;
+        };
+        let title = self
+            .saved_accounts
+            .iter()
+            .find(|acc| acc.username == draft.username)
+            .map(|acc| acc.full_name())
+            .unwrap_or_default();
+
+        let mut close = false;
+        let mut save = false;
+        egui::Window::new(t!("organize.title", name = title))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                egui::Grid::new("organize_grid")
+                    .num_columns(2)
+                    .spacing([10.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(t!("field.group"));
+                        ui.vertical(|ui| {
+                            ui.add(
+                                egui::TextEdit::singleline(&mut draft.group)
+                                    .hint_text(t!("field.group_hint"))
+                                    .desired_width(220.0),
+                            );
+                            if !groups.is_empty() {
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.set_max_width(220.0);
+                                    for group in &groups {
+                                        if ui.small_button(group).clicked() {
+                                            draft.group = group.clone();
+                                        }
                                     }
                                 });
-                            });
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(t!("field.labels"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut draft.labels)
+                                .hint_text(t!("field.labels_hint"))
+                                .desired_width(220.0),
+                        );
+                        ui.end_row();
+
+                        ui.label(t!("field.color"));
+                        ui.horizontal(|ui| {
+                            let mut has_color = draft.color.is_some();
+                            if ui.checkbox(&mut has_color, "").changed() {
+                                draft.color = has_color.then_some([230, 150, 40]);
+                            }
+                            if let Some(color) = &mut draft.color {
+                                ui.color_edit_button_srgb(color);
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(t!("field.notes"));
+                        ui.add(egui::TextEdit::multiline(&mut draft.notes).desired_rows(3).desired_width(220.0));
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    save = ui.button(t!("common.save")).clicked();
+                    close = ui.button(t!("common.cancel")).clicked();
+                });
+            });
+
+        if save && let Some(draft) = self.organize_draft.take() {
+            let group = credentials::normalize_group(&self.saved_accounts, &draft.username, &draft.group);
+            if let Some(account) = self.saved_accounts.iter_mut().find(|acc| acc.username == draft.username) {
+                let before = EditedFields::of(account);
+                account.group = group;
+                account.labels = draft
+                    .labels
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|label| !label.is_empty())
+                    .map(str::to_owned)
+                    .collect();
+                account.notes = draft.notes.trim().to_owned();
+                account.color = draft.color;
+                let description = t!("undo.organized", name = account.full_name());
+                self.record_change(AccountChange::Edited(vec![(draft.username.clone(), before)]), description);
+            }
+            if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+                self.alert_message = Some(t!("error.save_accounts", error = e));
+            }
+        } else if close {
+            self.organize_draft = None;
+        }
+    }
+
+    fn render_riot_id_confirmation(&mut self, ctx: &egui::Context) {
+        let Some(update) = &self.pending_riot_id else {
+            return;
+        };
+        let Some(account) = self.saved_accounts.iter().find(|acc| acc.username == update.username) else {
+            self.pending_riot_id = None;
+            return;
+        };
+
+        let new_name = if update.custom_tag.is_empty() {
+            format!("{}#{}", update.in_game_name, account.region)
+        } else {
+            format!("{}#{}", update.in_game_name, update.custom_tag)
+        };
+
+        let mut accepted = None;
+        egui::Window::new(t!("riot_id.title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(t!("riot_id.reports", username = account.username));
+                ui.add_space(5.0);
+                ui.label(format!("{}  →  {}", account.full_name(), new_name));
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(t!("riot_id.update")).clicked() {
+                        accepted = Some(true);
+                    }
+                    if ui.button(t!("riot_id.keep")).clicked() {
+                        accepted = Some(false);
+                    }
+                });
+            });
+
+        match accepted {
+            Some(true) => {
+                if let Some(update) = self.pending_riot_id.take() {
+                    self.apply_riot_id(update);
+                }
+            }
+            Some(false) => self.pending_riot_id = None,
+            None => {}
+        }
+    }
+
+    fn render_launch_status(&self, ui: &mut egui::Ui) {
+        if let Some(status) = &self.launch_status {
+            ui.add_space(10.0);
+            ui.label(egui::RichText::new(status).small().weak());
+        }
+    }
+
+    fn open_champ_select_view(&mut self) {
+        let Some(idx) = self.selected_account_index() else {
+            self.alert_message = Some(t!("error.choose_account").to_owned());
+            return;
+        };
+        let account = &self.saved_accounts[idx];
+
+        self.champ_select_username = account.username.clone();
+        self.champ_select_prefs = account.champ_select.clone();
+        self.champ_select_texts = champ_select::ROLES
+            .iter()
+            .map(|role| {
+                let join = |lists: &HashMap<String, Vec<String>>| {
+                    lists.get(*role).map(|names| names.join(", ")).unwrap_or_default()
+                };
+                (
+                    role.to_string(),
+                    (join(&account.champ_select.picks), join(&account.champ_select.bans)),
+                )
+            })
+            .collect();
+        self.current_view = View::ChampSelect;
+    }
+
+    fn save_champ_select_preferences(&mut self) {
+        let split = |text: &str| -> Vec<String> {
+            text.split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(str::to_owned)
+                .collect()
+        };
+
+        let mut prefs = self.champ_select_prefs.clone();
+        prefs.picks.clear();
+        prefs.bans.clear();
+        for (role, (picks, bans)) in &self.champ_select_texts {
+            let picks = split(picks);
+            let bans = split(bans);
+            if !picks.is_empty() {
+                prefs.picks.insert(role.clone(), picks);
+            }
+            if !bans.is_empty() {
+                prefs.bans.insert(role.clone(), bans);
+            }
+        }
+
+        let Some(account) = self.saved_accounts.iter_mut().find(|acc| acc.username == self.champ_select_username) else {
+            self.alert_message = Some(t!("error.account_missing").to_owned());
+            return;
+        };
+        account.champ_select = prefs;
+
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        } else {
+            self.sync_watcher_config();
+            self.current_view = View::Login;
+        }
+    }
+
+    fn render_champ_select_view(&mut self, ctx: &egui::Context) {
+        egui::Area::new(egui::Id::new("champ_select_back_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                if ui.add(egui::Button::new("⬅").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                    .on_hover_text(t!("common.back_without_saving"))
+                    .clicked()
+                {
+                    self.current_view = View::Login;
+                }
+            });
+
+        let account_name = self
+            .saved_accounts
+            .iter()
+            .find(|acc| acc.username == self.champ_select_username)
+            .map(|acc| acc.full_name())
+            .unwrap_or_default();
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(20.0);
+                    ui.heading(t!("champ_select.title"));
+                    ui.label(egui::RichText::new(account_name).weak());
+                    ui.add_space(20.0);
+
+                    toggle_row(ui, t!("champ_select.enabled"), &mut self.champ_select_prefs.enabled);
+                    ui.add_space(10.0);
+                    toggle_row(ui, t!("champ_select.lock_in"), &mut self.champ_select_prefs.lock_in);
+                    ui.label(
+                        egui::RichText::new(t!("champ_select.lock_in_hint"))
+                            .small()
+                            .weak(),
+                    );
+
+                    ui.add_space(20.0);
+                });
+
+                let field_width = 220.0;
+                let grid_width = 70.0 + (field_width + 8.0) * 2.0 + 30.0;
+                ui.horizontal(|ui| {
+                    ui.add_space(((ui.available_width() - grid_width) / 2.0).max(0.0));
+                    egui::Grid::new("champ_select_grid")
+                        .num_columns(3)
+                        .spacing([15.0, 10.0])
+                        .show(ui, |ui| {
+                            ui.label("");
+                            ui.strong(t!("champ_select.picks"));
+                            ui.strong(t!("champ_select.bans"));
                             ui.end_row();
 
-                            ui.label("In-game name:");
-                            ui.horizontal(|ui| {
-                                let tag_width = 45.0;
-                                let gap = ui.spacing().item_spacing.x; 
-                                let hash_width_approx = 10.0;
-                                
-                                let safety_margin = 6.5;
-
-                                let name_width = field_width - tag_width - (gap * 2.0) - hash_width_approx - safety_margin;
-
-                                let name_response = ui.add(egui::TextEdit::singleline(&mut self.in_game_name).desired_width(name_width));
-                                if name_response.changed() {
-                                    if self.in_game_name.contains('#') {
-                                        if let Some((name, tag)) = self.in_game_name.clone().split_once('#') {
-                                            self.in_game_name = name.to_string();
-                                            self.custom_tag = tag.to_string();
-                                        }
-                                    }
-                                }
+                            for role in champ_select::ROLES {
+                                let (picks, bans) = self.champ_select_texts.entry(role.to_string()).or_default();
+                                ui.label(champ_select::role_label(role));
+                                ui.add(egui::TextEdit::singleline(picks).desired_width(field_width));
+                                ui.add(egui::TextEdit::singleline(bans).desired_width(field_width));
+                                ui.end_row();
+                            }
+                        });
+                });
 
-                                ui.label("#");
-                                let tag_response = ui.add(
-                                    egui::TextEdit::singleline(&mut self.custom_tag)
-                                        .desired_width(tag_width)
-                                ).on_hover_text("Leave this area empty if u have standard tag based on region.");
+                ui.vertical_centered(|ui| {
+                    ui.add_space(10.0);
+                    ui.label(
+                        egui::RichText::new(t!("champ_select.hint"))
+                            .small()
+                            .weak(),
+                    );
+                    ui.add_space(20.0);
 
-                                if tag_response.changed() {
-                                    if self.custom_tag.chars().count() > 5 {
-                                        let truncated: String = self.custom_tag.chars().take(5).collect();
-                                        self.custom_tag = truncated;
+                    if ui.button(t!("common.save")).clicked() {
+                        self.save_champ_select_preferences();
+                    }
+                });
+            });
+        });
+    }
+
+    // Talks to the League client, so it runs on a worker thread like a launch.
+    fn import_rune_page_into_draft(&mut self) {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let result = lcu::LcuClient::connect().and_then(|client| {
+                let page = client.current_rune_page()?;
+                // Spells are only known while in champion select.
+                let spells = client
+                    .champ_select_session()
+                    .ok()
+                    .and_then(|session| session.local_player().map(|p| (p.spell1_id, p.spell2_id)));
+                Ok(RuneImport { page, spells })
+            });
+            let _ = tx.send(result);
+        });
+        self.rune_import = Some(rx);
+    }
+
+    fn poll_rune_import(&mut self, ctx: &egui::Context) {
+        let Some(import) = &self.rune_import else {
+            return;
+        };
+        let result = match import.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => {
+                ctx.request_repaint_after(std::time::Duration::from_millis(100));
+                return;
+            }
+            Err(mpsc::TryRecvError::Disconnected) => {
+                self.rune_import = None;
+                return;
+            }
+        };
+        self.rune_import = None;
+
+        match result {
+            Ok(RuneImport { page, spells }) => {
+                if self.preset_draft.name.trim().is_empty() {
+                    self.preset_draft.name = page.name.clone();
+                }
+                self.preset_draft.runes = Some(RunePreset {
+                    primary_style_id: page.primary_style_id,
+                    sub_style_id: page.sub_style_id,
+                    selected_perk_ids: page.selected_perk_ids,
+                });
+                if let Some((spell1_id, spell2_id)) = spells {
+                    self.preset_draft.spell1_id = spell1_id;
+                    self.preset_draft.spell2_id = spell2_id;
+                }
+            }
+            Err(e) => self.alert_message = Some(e),
+        }
+    }
+
+    fn save_preset_draft(&mut self) {
+        let mut preset = self.preset_draft.clone();
+        preset.champion = preset.champion.trim().to_owned();
+        preset.name = preset.name.trim().to_owned();
+        if preset.champion.is_empty() {
+            self.alert_message = Some(t!("error.champion_required").to_owned());
+            return;
+        }
+        if preset.runes.is_none() && !preset.has_spells() {
+            self.alert_message = Some(t!("error.preset_empty").to_owned());
+            return;
+        }
+        if preset.name.is_empty() {
+            preset.name = preset.champion.clone();
+        }
+
+        match self.preset_draft_index.filter(|idx| *idx < self.settings.presets.len()) {
+            Some(idx) => self.settings.presets[idx] = preset,
+            None => self.settings.presets.push(preset),
+        }
+
+        if let Err(e) = settings::save_settings(&self.settings) {
+            self.alert_message = Some(t!("error.save_settings", error = e));
+        }
+        self.sync_watcher_config();
+        self.preset_draft = LoadoutPreset::default();
+        self.preset_draft_index = None;
+    }
+
+    fn render_presets_view(&mut self, ctx: &egui::Context) {
+        self.poll_rune_import(ctx);
+
+        egui::Area::new(egui::Id::new("presets_back_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                if ui.add(egui::Button::new("⬅").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                    self.current_view = View::Login;
+                }
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(20.0);
+                    ui.heading(t!("presets.title"));
+                    ui.label(
+                        egui::RichText::new(t!("presets.subtitle"))
+                            .small()
+                            .weak(),
+                    );
+                    ui.add_space(15.0);
+
+                    if self.settings.presets.is_empty() {
+                        ui.label(egui::RichText::new(t!("presets.empty")).weak());
+                    }
+
+                    let mut remove = None;
+                    for (idx, preset) in self.settings.presets.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            let text = format!(
+                                "{} ({}) — {}: {}",
+                                preset.champion,
+                                champ_select::role_label(&preset.role),
+                                preset.name,
+                                preset.summary()
+                            );
+                            ui.add_space(((ui.available_width() - 420.0) / 2.0).max(0.0));
+                            ui.add_sized([360.0, 20.0], egui::Label::new(text).truncate());
+                            if ui.small_button("✏").on_hover_text(t!("common.edit")).clicked() {
+                                self.preset_draft = preset.clone();
+                                self.preset_draft_index = Some(idx);
+                            }
+                            if ui.small_button("🗑").on_hover_text(t!("common.delete")).clicked() {
+                                remove = Some(idx);
+                            }
+                        });
+                    }
+                    if let Some(idx) = remove {
+                        self.settings.presets.remove(idx);
+                        self.preset_draft_index = match self.preset_draft_index {
+                            Some(editing) if editing == idx => None,
+                            Some(editing) if editing > idx => Some(editing - 1),
+                            editing => editing,
+                        };
+                        let _ = settings::save_settings(&self.settings);
+                        self.sync_watcher_config();
+                    }
+
+                    ui.add_space(15.0);
+                    ui.separator();
+                    ui.add_space(10.0);
+                    ui.strong(if self.preset_draft_index.is_some() { t!("presets.edit") } else { t!("presets.new") });
+                    ui.add_space(10.0);
+                });
+
+                let grid_width = 90.0 + 15.0 + 260.0;
+                ui.horizontal(|ui| {
+                    ui.add_space(((ui.available_width() - grid_width) / 2.0).max(0.0));
+                    egui::Grid::new("preset_grid")
+                        .num_columns(2)
+                        .spacing([15.0, 10.0])
+                        .show(ui, |ui| {
+                            ui.label(t!("presets.champion"));
+                            ui.add(egui::TextEdit::singleline(&mut self.preset_draft.champion).desired_width(260.0));
+                            ui.end_row();
+
+                            ui.label(t!("presets.name"));
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.preset_draft.name)
+                                    .hint_text(t!("presets.name_hint"))
+                                    .desired_width(260.0),
+                            );
+                            ui.end_row();
+
+                            ui.label(t!("presets.role"));
+                            egui::ComboBox::from_id_salt("preset_role_combo")
+                                .selected_text(champ_select::role_label(&self.preset_draft.role))
+                                .width(260.0)
+                                .show_ui(ui, |ui| {
+                                    for role in champ_select::ROLES {
+                                        ui.selectable_value(&mut self.preset_draft.role, role.to_owned(), champ_select::role_label(role));
                                     }
+                                });
+                            ui.end_row();
+
+                            ui.label(t!("presets.spells"));
+                            ui.horizontal(|ui| {
+                                for (salt, spell) in [
+                                    ("preset_spell1_combo", &mut self.preset_draft.spell1_id),
+                                    ("preset_spell2_combo", &mut self.preset_draft.spell2_id),
+                                ] {
+                                    egui::ComboBox::from_id_salt(salt)
+                                        .selected_text(presets::spell_name(*spell))
+                                        .width(120.0)
+                                        .show_ui(ui, |ui| {
+                                            ui.selectable_value(spell, 0, t!("common.none"));
+                                            for id in presets::SUMMONER_SPELLS {
+                                                ui.selectable_value(spell, id, presets::spell_name(id));
+                                            }
+                                        });
                                 }
                             });
                             ui.end_row();
 
-                            ui.label("Region:");
-                            egui::ComboBox::from_id_salt("region_combo")
-                                .selected_text(&self.region)
-                                .width(field_width + 8.0) 
-                                .show_ui(ui, |ui| {
-                                    ui.selectable_value(&mut self.region, "EUNE".to_string(), "EUNE");
-                                    ui.selectable_value(&mut self.region, "EUW".to_string(), "EUW");
-                                    ui.selectable_value(&mut self.region, "NA".to_string(), "NA");
-                                });
+                            ui.label(t!("presets.runes"));
+                            ui.horizontal(|ui| {
+                                let runes_text = match &self.preset_draft.runes {
+                                    Some(runes) => t!("presets.rune_count", count = runes.selected_perk_ids.len()),
+                                    None => t!("presets.not_set").to_owned(),
+                                };
+                                ui.label(runes_text);
+                                let importing = self.rune_import.is_some();
+                                if ui.add_enabled(!importing, egui::Button::new(t!("presets.import")))
+                                    .on_hover_text(t!("presets.import_hint"))
+                                    .clicked()
+                                {
+                                    self.import_rune_page_into_draft();
+                                }
+                                if importing {
+                                    ui.spinner();
+                                }
+                                if self.preset_draft.runes.is_some() && ui.small_button("✖").clicked() {
+                                    self.preset_draft.runes = None;
+                                }
+                            });
                             ui.end_row();
                         });
                 });
 
+                ui.vertical_centered(|ui| {
+                    ui.add_space(15.0);
+                    ui.horizontal(|ui| {
+                        ui.add_space(((ui.available_width() - 160.0) / 2.0).max(0.0));
+                        if ui.button(t!("presets.save")).clicked() {
+                            self.save_preset_draft();
+                        }
+                        if ui.button(t!("presets.clear")).clicked() {
+                            self.preset_draft = LoadoutPreset::default();
+                            self.preset_draft_index = None;
+                        }
+                    });
+                });
+            });
+        });
+    }
+
+    fn clear_selected_account(&mut self) {
+        self.selected_account_display = t!("picker.placeholder").to_owned();
+        self.username.clear();
+        self.password.clear();
+        self.in_game_name.clear();
+        self.custom_tag.clear();
+        self.language.clear();
+    }
+
+    fn save_account_draft(&mut self) {
+        let Some(draft) = &self.account_draft else {
+            return;
+        };
+        let username = draft.username.trim().to_owned();
+        if username.is_empty() {
+            self.alert_message = Some(t!("error.username_empty").to_owned());
+            return;
+        }
+        if self
+            .saved_accounts
+            .iter()
+            .any(|acc| acc.username == username && draft.original_username.as_ref() != Some(&acc.username))
+        {
+            self.alert_message = Some(t!("error.username_taken", username = username));
+            return;
+        }
+
+        let Some(draft) = self.account_draft.take() else {
+            return;
+        };
+        let launcher_username = self.selected_account_index().map(|idx| self.saved_accounts[idx].username.clone());
+        let existing = draft
+            .original_username
+            .as_ref()
+            .and_then(|original| self.saved_accounts.iter().position(|acc| &acc.username == original));
+
+        let own_username = draft.original_username.as_deref().unwrap_or(&username);
+        let group = credentials::normalize_group(&self.saved_accounts, own_username, &draft.group);
+        let change = match existing {
+            // Editing keeps the stats, history and organization of the account.
+            Some(idx) => {
+                let before = EditedFields::of(&self.saved_accounts[idx]);
+                let account = &mut self.saved_accounts[idx];
+                account.username = username.clone();
+                account.password = draft.password;
+                account.region = draft.region;
+                account.in_game_name = draft.in_game_name;
+                account.custom_tag = draft.custom_tag;
+                account.language = draft.language;
+                account.group = group;
+                account.auto_accept = draft.auto_accept;
+                (AccountChange::Edited(vec![(username.clone(), before)]), t!("undo.edited", name = account.full_name()))
+            }
+            None => {
+                let mut account = Account::new(
+                    username.clone(),
+                    draft.password,
+                    draft.region,
+                    draft.in_game_name,
+                    draft.custom_tag,
+                    draft.language,
+                );
+                account.group = group;
+                account.auto_accept = draft.auto_accept;
+                let description = t!("undo.added", name = account.full_name());
+                credentials::upsert_account(&mut self.saved_accounts, account);
+                (AccountChange::Added(username.clone()), description)
+            }
+        };
+        self.record_change(change.0, change.1);
+
+        if let Some(original) = &draft.original_username
+            && self.manager_selected.remove(original)
+        {
+            self.manager_selected.insert(username.clone());
+        }
+        if let Some(original) = &draft.original_username
+            && let Err(e) = match_history::rename(original, &username)
+        {
+            self.alert_message = Some(t!("error.move_history", name = username, error = e));
+        }
+        self.sync_watcher_config();
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+
+        // A new account, or the one chosen in the launcher, becomes the launcher's selection.
+        if (existing.is_none() || launcher_username == draft.original_username)
+            && let Some(idx) = self.saved_accounts.iter().position(|acc| acc.username == username)
+        {
+            self.select_account(idx);
+        }
+    }
+
+    fn move_manager_selection_to_group(&mut self) {
+        let group = self.manager_group_target.trim().to_owned();
+        let mut edits = Vec::new();
+        for account in self
+            .saved_accounts
+            .iter_mut()
+            .filter(|acc| self.manager_selected.contains(&acc.username) && acc.group != group)
+        {
+            edits.push((account.username.clone(), EditedFields::of(account)));
+            account.group = group.clone();
+        }
+        if edits.is_empty() {
+            return;
+        }
+
+        let description = if group.is_empty() {
+            t!("undo.ungrouped", accounts = account_count(edits.len()))
+        } else {
+            t!("undo.grouped", accounts = account_count(edits.len()), group = group)
+        };
+        self.record_change(AccountChange::Edited(edits), description);
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+    }
+
+    fn delete_manager_selection(&mut self) {
+        let launcher_username = self.selected_account_index().map(|idx| self.saved_accounts[idx].username.clone());
+        let deleted: Vec<(usize, Account)> = self
+            .saved_accounts
+            .iter()
+            .enumerate()
+            .filter(|(_, acc)| self.manager_selected.contains(&acc.username))
+            .map(|(idx, acc)| (idx, acc.clone()))
+            .collect();
+        if deleted.is_empty() {
+            return;
+        }
+        self.saved_accounts.retain(|acc| !self.manager_selected.contains(&acc.username));
+
+        let now = credentials::unix_now();
+        trash::purge_expired(&mut self.trash, now);
+        self.trash.extend(deleted.iter().map(|(_, account)| TrashedAccount { account: account.clone(), deleted_at: now }));
+        if let Err(e) = trash::save_trash(&self.trash) {
+            self.alert_message = Some(t!("error.save_trash", error = e));
+        }
+        let description = match deleted.as_slice() {
+            [(_, account)] => t!("undo.deleted", name = account.full_name()),
+            _ => t!("undo.deleted", name = account_count(deleted.len())),
+        };
+        self.record_change(AccountChange::Deleted(deleted), description);
+
+        if launcher_username.is_some_and(|username| self.manager_selected.contains(&username)) {
+            self.clear_selected_account();
+        }
+        if self
+            .account_draft
+            .as_ref()
+            .and_then(|draft| draft.original_username.as_ref())
+            .is_some_and(|username| self.manager_selected.contains(username))
+        {
+            self.account_draft = None;
+        }
+        self.manager_selected.clear();
+        self.sync_watcher_config();
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+    }
+
+    fn move_account(&mut self, from: usize, target: usize) {
+        let Some(to) = drop_position(from, target, self.saved_accounts.len()) else {
+            return;
+        };
+        let account = self.saved_accounts.remove(from);
+        let description = t!("undo.moved", name = account.full_name());
+        self.saved_accounts.insert(to, account);
+        self.record_change(AccountChange::Moved { from, to }, description);
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+    }
+
+    fn record_change(&mut self, change: AccountChange, description: String) {
+        self.undo_stack.push(UndoEntry { change, description, at: std::time::Instant::now() });
+        if self.undo_stack.len() > UNDO_LIMIT {
+            self.undo_stack.remove(0);
+        }
+    }
+
+    fn undo_last_change(&mut self) {
+        let Some(entry) = self.undo_stack.pop() else {
+            return;
+        };
+
+        // Follow renames back, so the launcher and the manager keep their selection.
+        if let AccountChange::Edited(edits) = &entry.change {
+            for (username, before) in edits {
+                if *username == before.username {
+                    continue;
+                }
+                if self.username == *username {
+                    self.username = before.username.clone();
+                }
+                if self.manager_selected.remove(username) {
+                    self.manager_selected.insert(before.username.clone());
+                }
+                if let Err(e) = match_history::rename(username, &before.username) {
+                    self.alert_message = Some(t!("error.move_history", name = before.username, error = e));
+                }
+            }
+        }
+
+        let touches_trash = matches!(entry.change, AccountChange::Deleted(_) | AccountChange::Restored(_));
+        entry.change.revert(&mut self.saved_accounts, &mut self.trash);
+        if touches_trash {
+            trash::purge_expired(&mut self.trash, credentials::unix_now());
+            if let Err(e) = trash::save_trash(&self.trash) {
+                self.alert_message = Some(t!("error.save_trash", error = e));
+            }
+        }
+
+        self.refresh_selected_account();
+        self.sync_watcher_config();
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+    }
+
+    // Re-reads the launcher selection from the saved accounts, clearing it when the account is gone.
+    fn refresh_selected_account(&mut self) {
+        match self.saved_accounts.iter().position(|acc| acc.username == self.username) {
+            Some(idx) => self.select_account(idx),
+            None => self.clear_selected_account(),
+        }
+    }
+
+    // Shown for a few seconds after each change; Ctrl+Z undoes the last change at any time.
+    fn render_undo_toast(&mut self, ctx: &egui::Context) {
+        let shortcut = !ctx.wants_keyboard_input()
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Z));
+
+        let mut undo = shortcut && !self.undo_stack.is_empty();
+        if let Some(entry) = self.undo_stack.last() {
+            let shown_for = std::time::Duration::from_secs(UNDO_TOAST_SECS);
+            let elapsed = entry.at.elapsed();
+            if elapsed < shown_for {
+                egui::Area::new(egui::Id::new("undo_toast"))
+                    .anchor(egui::Align2::CENTER_BOTTOM, egui::vec2(0.0, -12.0))
+                    .show(ctx, |ui| {
+                        egui::Frame::popup(ui.style()).show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(&entry.description);
+                                if ui.button(t!("undo.undo")).clicked() {
+                                    undo = true;
+                                }
+                            });
+                        });
+                    });
+                ctx.request_repaint_after(shown_for - elapsed);
+            }
+        }
+
+        if undo {
+            self.undo_last_change();
+        }
+    }
+
+    fn restore_from_trash(&mut self, idx: usize) {
+        let username = &self.trash[idx].account.username;
+        if self.saved_accounts.iter().any(|acc| &acc.username == username) {
+            self.alert_message = Some(t!("error.username_taken", username = username));
+            return;
+        }
+
+        let entry = self.trash.remove(idx);
+        let description = t!("undo.restored", name = entry.account.full_name());
+        self.saved_accounts.push(entry.account.clone());
+        self.record_change(AccountChange::Restored(Box::new(entry)), description);
+        self.sync_watcher_config();
+        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
+            self.alert_message = Some(t!("error.save_accounts", error = e));
+        }
+        if let Err(e) = trash::save_trash(&self.trash) {
+            self.alert_message = Some(t!("error.save_trash", error = e));
+        }
+    }
+
+    fn render_trash(&mut self, ui: &mut egui::Ui) {
+        let now = credentials::unix_now();
+        ui.label(
+            egui::RichText::new(t!("trash.retention", days = trash::RETENTION_DAYS))
+                .small()
+                .weak(),
+        );
+        if self.trash.is_empty() {
+            ui.weak(t!("trash.empty"));
+            return;
+        }
+
+        let mut restore = None;
+        let mut purge = None;
+        egui::ScrollArea::vertical().id_salt("trash_scroll").max_height(120.0).show(ui, |ui| {
+            for (idx, entry) in self.trash.iter().enumerate().rev() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} ({})", entry.account.full_name(), entry.account.region));
+                    ui.weak(t!("trash.days_left", days = entry.days_left(now)));
+                    if ui.small_button(t!("trash.restore")).clicked() {
+                        restore = Some(idx);
+                    }
+                    if ui.small_button(t!("trash.delete_forever")).clicked() {
+                        purge = Some(idx);
+                    }
+                });
+            }
+        });
+
+        if let Some(idx) = restore {
+            self.restore_from_trash(idx);
+        } else if let Some(idx) = purge {
+            self.trash.remove(idx);
+            if let Err(e) = trash::save_trash(&self.trash) {
+                self.alert_message = Some(t!("error.save_trash", error = e));
+            }
+        }
+    }
+
+    fn render_delete_confirmation(&mut self, ctx: &egui::Context) {
+        if !self.show_delete_confirmation {
+            return;
+        }
+
+        egui::Window::new(t!("manager.confirm_title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(t!("manager.confirm_delete", count = self.manager_selected.len()));
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button(t!("common.yes")).clicked() {
+                        self.delete_manager_selection();
+                        self.show_delete_confirmation = false;
+                    }
+                    if ui.button(t!("common.no")).clicked() {
+                        self.show_delete_confirmation = false;
+                    }
+                });
+            });
+    }
+
+    fn render_accounts_view(&mut self, ctx: &egui::Context) {
+        self.render_delete_confirmation(ctx);
+
+        egui::Area::new(egui::Id::new("accounts_back_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                if ui.add(egui::Button::new("⬅").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                    self.dragged_account_idx = None;
+                    self.current_view = View::Login;
+                }
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(t!("manager.title"));
+                ui.label(
+                    egui::RichText::new(t!("manager.subtitle"))
+                        .small()
+                        .weak(),
+                );
+                ui.add_space(15.0);
+            });
+
+            ui.horizontal(|ui| {
+                if ui.button(t!("manager.add")).clicked() {
+                    self.account_draft = Some(AccountDraft::new_account());
+                }
+                ui.separator();
+                let count = self.manager_selected.len();
+                ui.label(t!("manager.selected", count = count));
+                ui.add_enabled_ui(count > 0, |ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.manager_group_target)
+                            .hint_text(t!("manager.group_hint"))
+                            .desired_width(100.0),
+                    );
+                    if ui.button(t!("manager.move_to_group")).on_hover_text(t!("manager.move_to_group_hint")).clicked() {
+                        self.move_manager_selection_to_group();
+                    }
+                    if ui.button(t!("manager.delete")).clicked() {
+                        self.show_delete_confirmation = true;
+                    }
+                });
+                ui.separator();
+                let trash_label = t!("trash.button", count = self.trash.len());
+                if ui.toggle_value(&mut self.show_trash, trash_label).clicked()
+                    && self.show_trash
+                    && trash::purge_expired(&mut self.trash, credentials::unix_now())
+                    && let Err(e) = trash::save_trash(&self.trash)
+                {
+                    self.alert_message = Some(t!("error.save_trash", error = e));
+                }
+            });
+            ui.separator();
+
+            if self.show_trash {
+                self.render_trash(ui);
+                ui.separator();
+            }
+
+            if self.saved_accounts.is_empty() && self.account_draft.is_none() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(20.0);
+                    ui.label(egui::RichText::new(t!("manager.empty")).weak());
+                });
+                return;
+            }
+
+            let groups = credentials::group_names(&self.saved_accounts);
+            let mut form_action = (false, false);
+            let mut edit = None;
+            let mut drag_started = None;
+            let mut drop_target = None;
+
+            egui::ScrollArea::both().show(ui, |ui| {
+                if let Some(draft) = self.account_draft.as_mut().filter(|draft| draft.original_username.is_none()) {
+                    form_action = account_form(ui, draft, &mut self.show_password, &groups);
+                    ui.add_space(6.0);
+                }
+
+                ui.horizontal(|ui| {
+                    let mut all = self.saved_accounts.iter().all(|acc| self.manager_selected.contains(&acc.username));
+                    if table_cell(ui, 22.0, |ui| ui.checkbox(&mut all, "")).changed() {
+                        self.manager_selected.clear();
+                        if all {
+                            self.manager_selected.extend(self.saved_accounts.iter().map(|acc| acc.username.clone()));
+                        }
+                    }
+                    table_cell(ui, 18.0, |_| {});
+                    for (title, width) in manager_columns() {
+                        table_cell(ui, width, |ui| ui.strong(title));
+                    }
+                });
+                ui.separator();
+
+                let mut row_rects = Vec::with_capacity(self.saved_accounts.len());
+                for (idx, account) in self.saved_accounts.iter().enumerate() {
+                    let row = ui.horizontal(|ui| {
+                        let mut checked = self.manager_selected.contains(&account.username);
+                        if table_cell(ui, 22.0, |ui| ui.checkbox(&mut checked, "")).changed() {
+                            if checked {
+                                self.manager_selected.insert(account.username.clone());
+                            } else {
+                                self.manager_selected.remove(&account.username);
+                            }
+                        }
+
+                        let handle = table_cell(ui, 18.0, |ui| ui.add(egui::Label::new("⠿").sense(egui::Sense::drag())))
+                            .on_hover_cursor(egui::CursorIcon::Grab);
+                        if handle.drag_started() {
+                            drag_started = Some(idx);
+                        }
+
+                        let [riot_id, region, username, group, last_used, rank] = manager_columns().map(|(_, width)| width);
+                        table_cell(ui, riot_id, |ui| ui.add(egui::Label::new(account.full_name()).truncate()));
+                        table_cell(ui, region, |ui| ui.label(&account.region));
+                        table_cell(ui, username, |ui| ui.add(egui::Label::new(&account.username).truncate()));
+                        table_cell(ui, group, |ui| {
+                            if account.group.is_empty() {
+                                ui.weak("—")
+                            } else {
+                                ui.add(egui::Label::new(&account.group).truncate())
+                            }
+                        });
+                        table_cell(ui, last_used, |ui| {
+                            let text = account.last_used_at.map(format_age).unwrap_or_else(|| "—".to_owned());
+                            let outcome = account.last_outcome.map(|o| format!("{} ", o.icon())).unwrap_or_default();
+                            ui.label(format!("{}{}", outcome, text))
+                        });
+                        table_cell(ui, rank, |ui| {
+                            match account.stats.as_ref().and_then(|stats| stats.solo_duo.as_ref()) {
+                                Some(solo) => ui.colored_label(tier_color(&solo.tier), solo.short_label()),
+                                None => ui.weak(t!("common.unranked")),
+                            }
+                        });
+                        if ui.small_button("✏").on_hover_text(t!("common.edit")).clicked() {
+                            edit = Some(idx);
+                        }
+                    });
+                    let rect = row.response.rect;
+                    row_rects.push(rect);
+                    if self.dragged_account_idx == Some(idx) {
+                        ui.painter().rect_filled(
+                            rect,
+                            ui.visuals().widgets.inactive.corner_radius,
+                            ui.visuals().widgets.inactive.bg_fill.linear_multiply(0.5),
+                        );
+                    }
+
+                    if let Some(draft) = self
+                        .account_draft
+                        .as_mut()
+                        .filter(|draft| draft.original_username.as_ref() == Some(&account.username))
+                    {
+                        form_action = account_form(ui, draft, &mut self.show_password, &groups);
+                    }
+                }
+
+                if let Some(from) = self.dragged_account_idx
+                    && let Some(pointer) = ui.ctx().pointer_interact_pos()
+                {
+                    let target = row_rects.iter().filter(|rect| rect.center().y < pointer.y).count();
+                    let y = match row_rects.get(target) {
+                        Some(rect) => rect.top(),
+                        None => row_rects.last().map_or(pointer.y, |rect| rect.bottom()),
+                    };
+                    ui.painter().hline(ui.min_rect().x_range(), y, ui.visuals().selection.stroke);
+                    drop_target = Some((from, target));
+
+                    let layer_id = egui::LayerId::new(egui::Order::Tooltip, egui::Id::new("drag_ghost"));
+                    let painter = ui.ctx().layer_painter(layer_id);
+                    let widget = ui.visuals().widgets.active;
+                    let galley = painter.layout_no_wrap(
+                        self.saved_accounts[from].full_name(),
+                        egui::TextStyle::Body.resolve(ui.style()),
+                        widget.text_color(),
+                    );
+                    let ghost_rect = egui::Rect::from_min_size(
+                        pointer + egui::vec2(12.0, -galley.size().y / 2.0 - 4.0),
+                        galley.size() + egui::vec2(12.0, 8.0),
+                    );
+                    painter.rect_filled(ghost_rect.translate(egui::vec2(2.0, 2.0)), widget.corner_radius, Palette::of(ui.visuals()).shadow);
+                    painter.rect(ghost_rect, widget.corner_radius, widget.bg_fill, widget.bg_stroke, egui::StrokeKind::Outside);
+                    painter.galley(ghost_rect.min + egui::vec2(6.0, 4.0), galley, widget.text_color());
+
+                    ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+                    ui.ctx().request_repaint();
+                }
+            });
+
+            if let Some(idx) = drag_started {
+                self.dragged_account_idx = Some(idx);
+            }
+            if self.dragged_account_idx.is_some() && ui.input(|i| i.pointer.any_released()) {
+                self.dragged_account_idx = None;
+                if let Some((from, target)) = drop_target {
+                    self.move_account(from, target);
+                }
+            }
+
+            if let Some(idx) = edit {
+                self.account_draft = Some(AccountDraft::from_account(&self.saved_accounts[idx]));
+            }
+            match form_action {
+                (true, _) => self.save_account_draft(),
+                (_, true) => self.account_draft = None,
+                _ => {}
+            }
+        });
+    }
+
+    fn sorted_account_indices(&self) -> Vec<usize> {
+        let column = self.details_sort;
+        let mut order: Vec<usize> = (0..self.saved_accounts.len()).collect();
+
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&self.saved_accounts[a], &self.saved_accounts[b]);
+            if column == DetailsColumn::Account {
+                let ordering = a.full_name().to_lowercase().cmp(&b.full_name().to_lowercase());
+                return if self.details_sort_descending { ordering.reverse() } else { ordering };
+            }
+            // Accounts without a snapshot stay at the bottom in both directions.
+            match (column.value(a), column.value(b)) {
+                (Some(a), Some(b)) if self.details_sort_descending => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            }
+        });
+
+        order
+    }
+
+    fn render_account_details_view(&mut self, ctx: &egui::Context) {
+        egui::Area::new(egui::Id::new("account_details_back_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                if ui.add(egui::Button::new("⬅").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                    self.current_view = View::Login;
+                }
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(t!("details.title"));
+                ui.label(
+                    egui::RichText::new(t!("details.subtitle"))
+                        .small()
+                        .weak(),
+                );
+                ui.add_space(15.0);
+            });
+
+            let order = self.sorted_account_indices();
+            let mut selected = None;
+
+            egui::ScrollArea::both().show(ui, |ui| {
+                egui::Grid::new("account_details_grid")
+                    .num_columns(DetailsColumn::ALL.len())
+                    .striped(true)
+                    .spacing([14.0, 6.0])
+                    .show(ui, |ui| {
+                        for column in DetailsColumn::ALL {
+                            let is_sorted = self.details_sort == column;
+                            let arrow = match (is_sorted, self.details_sort_descending) {
+                                (false, _) => "",
+                                (true, false) => " ▲",
+                                (true, true) => " ▼",
+                            };
+                            let header = egui::RichText::new(format!("{}{}", column.label(), arrow)).strong();
+                            if ui.selectable_label(is_sorted, header).clicked() {
+                                if is_sorted {
+                                    self.details_sort_descending = !self.details_sort_descending;
+                                } else {
+                                    self.details_sort = column;
+                                    // Numbers are most useful largest first.
+                                    self.details_sort_descending = column != DetailsColumn::Account;
+                                }
+                            }
+                        }
+                        ui.end_row();
+
+                        for idx in order {
+                            let account = &self.saved_accounts[idx];
+                            for column in DetailsColumn::ALL {
+                                match column {
+                                    DetailsColumn::Account => {
+                                        let name = format!("{} ({})", account.full_name(), account.region);
+                                        if ui.link(name).clicked() {
+                                            selected = Some(idx);
+                                        }
+                                    }
+                                    DetailsColumn::LastUsed => {
+                                        let text = column.value(account).map(format_age).unwrap_or_else(|| "—".to_owned());
+                                        let outcome = account.last_outcome.map(|o| format!("{} ", o.icon())).unwrap_or_default();
+                                        let response = ui.label(format!("{}{}", outcome, text));
+                                        if let Some(outcome) = account.last_outcome {
+                                            response.on_hover_text(outcome.label());
+                                        }
+                                    }
+                                    DetailsColumn::Updated => {
+                                        let text = column.value(account).map(format_age).unwrap_or_else(|| "—".to_owned());
+                                        ui.label(egui::RichText::new(text).weak());
+                                    }
+                                    _ => {
+                                        let text = column.value(account).map(format_amount).unwrap_or_else(|| "—".to_owned());
+                                        ui.label(text);
+                                    }
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            if let Some(idx) = selected {
+                self.select_account(idx);
+                self.current_view = View::Login;
+            }
+        });
+    }
+
+    fn store_matches(&mut self, username: &str, matches: &[StoredMatch]) {
+        match match_history::append(username, matches) {
+            Ok(added) if added > 0 && self.history_username == username => {
+                self.history_matches = match_history::load(username);
+            }
+            Ok(_) => {}
+            Err(e) => self.alert_message = Some(t!("error.save_history", error = e)),
+        }
+    }
+
+    fn open_match_history(&mut self, username: &str) {
+        self.history_username = username.to_owned();
+        self.history_matches = match_history::load(username);
+        self.history_queue_filter = None;
+        self.current_view = View::MatchHistory;
+    }
+
+    fn render_match_history_view(&mut self, ctx: &egui::Context) {
+        egui::Area::new(egui::Id::new("match_history_back_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                if ui.add(egui::Button::new("⬅").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                    self.current_view = View::Login;
+                }
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(t!("history.title"));
+                ui.add_space(15.0);
+            });
+
+            let mut open_username = None;
+            ui.horizontal(|ui| {
+                let current = self
+                    .saved_accounts
+                    .iter()
+                    .find(|acc| acc.username == self.history_username)
+                    .map(|acc| acc.full_name())
+                    .unwrap_or_default();
+                egui::ComboBox::from_id_salt("history_account_combo")
+                    .selected_text(current)
+                    .width(200.0)
+                    .show_ui(ui, |ui| {
+                        for account in &self.saved_accounts {
+                            let is_current = account.username == self.history_username;
+                            if ui.selectable_label(is_current, account.full_name()).clicked() && !is_current {
+                                open_username = Some(account.username.clone());
+                            }
+                        }
+                    });
+
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.history_champion_filter)
+                        .hint_text(t!("history.champion_hint"))
+                        .desired_width(120.0),
+                );
+
+                let mut queues: Vec<i32> = self.history_matches.iter().map(|m| m.queue_id).collect();
+                queues.sort_unstable();
+                queues.dedup();
+                let queue_text = self
+                    .history_queue_filter
+                    .map(match_history::queue_name)
+                    .unwrap_or_else(|| t!("history.all_queues").to_owned());
+                egui::ComboBox::from_id_salt("history_queue_combo")
+                    .selected_text(queue_text)
+                    .width(140.0)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.history_queue_filter, None, t!("history.all_queues"));
+                        for queue in queues {
+                            ui.selectable_value(&mut self.history_queue_filter, Some(queue), match_history::queue_name(queue));
+                        }
+                    });
+            });
+            if let Some(username) = open_username {
+                self.open_match_history(&username);
+            }
+
+            let champion_filter = self.history_champion_filter.trim().to_lowercase();
+            let filtered: Vec<&StoredMatch> = self
+                .history_matches
+                .iter()
+                .filter(|m| champion_filter.is_empty() || m.champion.to_lowercase().contains(&champion_filter))
+                .filter(|m| self.history_queue_filter.is_none_or(|queue| m.queue_id == queue))
+                .collect();
+
+            ui.add_space(10.0);
+            if filtered.is_empty() {
+                ui.label(egui::RichText::new(t!("history.empty")).weak());
+                return;
+            }
+
+            let wins = filtered.iter().filter(|m| m.win).count();
+            let average_kda = filtered.iter().map(|m| m.kda()).sum::<f32>() / filtered.len() as f32;
+            ui.label(t!(
+                "history.summary",
+                games = filtered.len(),
+                win_rate = wins * 100 / filtered.len(),
+                kda = format!("{:.2}", average_kda),
+            ));
+            ui.add_space(10.0);
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("match_history_grid")
+                    .num_columns(6)
+                    .striped(true)
+                    .spacing([14.0, 6.0])
+                    .show(ui, |ui| {
+                        let headers = [
+                            t!("history.date"),
+                            t!("history.champion"),
+                            t!("history.queue"),
+                            t!("history.result"),
+                            t!("history.kda"),
+                            t!("history.duration"),
+                        ];
+                        for header in headers {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+
+                        for game in filtered {
+                            ui.label(restrictions::format_date(game.played_at));
+                            ui.label(&game.champion);
+                            ui.label(match_history::queue_name(game.queue_id));
+                            let palette = Palette::of(ui.visuals());
+                            if game.win {
+                                ui.colored_label(palette.success, t!("history.victory"));
+                            } else {
+                                ui.colored_label(palette.danger, t!("history.defeat"));
+                            }
+                            ui.label(format!("{} / {} / {}", game.kills, game.deaths, game.assists));
+                            ui.label(format!("{}:{:02}", game.duration_secs / 60, game.duration_secs % 60));
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+    }
+
+    fn render_settings_view(&mut self, ctx: &egui::Context) {
+        egui::Area::new(egui::Id::new("settings_gear_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                 if ui.add(egui::Button::new("⚙").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                     if let Err(e) = settings::save_settings(&self.settings) {
+                         self.alert_message = Some(t!("error.save_settings", error = e));
+                     }
+                     self.settings_unsaved = false;
+                     self.riot_path_draft = None;
+                     self.riot_install_check = riot_client::InstallCheck::default();
+                     self.current_view = View::Login;
+                 }
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(t!("settings.title"));
                 ui.add_space(15.0);
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.settings_search)
+                        .hint_text(t!("settings.search"))
+                        .desired_width(300.0),
+                );
+            });
+            ui.add_space(5.0);
 
-                ui.add_space(25.0);
-
+            let mut edits = Vec::new();
+            egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                let width = ui.available_width().min(520.0);
                 ui.horizontal(|ui| {
-                    ui.add_space(final_margin); 
-                    egui::Grid::new("account_select_grid")
-                        .num_columns(2)
-                        .min_col_width(label_col_width) 
-                        .spacing(grid_spacing)
-                        .show(ui, |ui| {
-                            ui.label("Choose account:");
-                            ui.horizontal(|ui| {
-                                let (display_name, display_region) = {
-                                    let parts: Vec<&str> = self.selected_account_display.split("           ").collect();
-                                    let name = parts.first().map(|s| s.to_string()).unwrap_or_else(|| self.selected_account_display.clone());
-                                    let region = parts.get(1).map(|s| s.to_string());
-                                    (name, region)
-                                };
-
-                                let current_drag_idx = self.dragged_account_idx;
-                                
-                                let combo_response = egui::ComboBox::from_id_salt("account_combo")
-                                    .selected_text(&display_name)
-                                    .width(field_width + 8.0)
-                                    .height(250.0)
-                                    .show_ui(ui, |ui| {
-                                        let mut swap_request = None;
-                                        let mut new_drag_idx = None;
-                                        let mut selected_account_idx = None;
-
-                                        for (idx, account) in self.saved_accounts.iter().enumerate() {
-                                            let label = format!("{}           {}", account.full_name(), account.region);
-                                            let is_selected = self.selected_account_display == label;
-
-                                            let font_id = egui::TextStyle::Body.resolve(ui.style());
-                                            let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
-                                            
-                                            let (rect, response) = ui.allocate_exact_size(
-                                                egui::vec2(ui.available_width(), row_height), 
-                                                egui::Sense::click_and_drag()
-                                            );
-                                            
-                                            if response.drag_started() {
-                                                new_drag_idx = Some(idx);
-                                                if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                                                     self.drag_offset = Some(pointer_pos - rect.min);
-                                                }
-                                            }
-
-                                            if let Some(dragged_idx) = current_drag_idx {
-                                                if dragged_idx != idx {
-                                                    if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                                                        if rect.contains(pointer_pos) {
-                                                            let center_y = rect.center().y;
-                                                            let should_swap = if dragged_idx < idx {
-                                                                pointer_pos.y > center_y
-                                                            } else {
-                                                                pointer_pos.y < center_y
-                                                            };
-
-                                                            if should_swap {
-                                                                swap_request = Some((dragged_idx, idx));
-                                                            }
-                                                        }
-                                                    }
-                                                }
-                                            }
-                                            
-                                            if response.clicked() && !response.dragged() {
-                                                selected_account_idx = Some(idx);
-                                            }
-
-                                            let is_being_dragged = current_drag_idx == Some(idx);
-
-                                            if is_being_dragged {
-                                                let corner_radius = ui.visuals().widgets.inactive.corner_radius;
-                                                ui.painter().rect_filled(
-                                                    rect, 
-                                                    corner_radius, 
-                                                    ui.visuals().widgets.inactive.bg_fill.linear_multiply(0.5)
-                                                );
-                                                
-                                                if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                                                    let layer_id = egui::LayerId::new(egui::Order::Tooltip, egui::Id::new("drag_ghost"));
-                                                    let painter = ui.ctx().layer_painter(layer_id);
-                                                    
-                                                    let ghost_pos = if let Some(offset) = self.drag_offset {
-                                                        pointer_pos - offset
-                                                    } else {
-                                                        pointer_pos + egui::vec2(10.0, 10.0)
-                                                    };
-
-                                                    let ghost_rect = egui::Rect::from_min_size(ghost_pos, rect.size());
-                                                    
-                                                    let ghost_bg = ui.visuals().widgets.active.bg_fill;
-                                                    let ghost_stroke = ui.visuals().widgets.active.bg_stroke;
-                                                    let ghost_text_color = ui.visuals().widgets.active.text_color();
-                                                    let ghost_radius = ui.visuals().widgets.active.corner_radius;
-                                                    
-                                                    let shadow_rect = ghost_rect.translate(egui::vec2(2.0, 2.0));
-                                                    painter.rect_filled(shadow_rect, ghost_radius, egui::Color32::from_black_alpha(100));
-
-                                                    painter.rect_filled(ghost_rect, ghost_radius, ghost_bg);
-                                                    painter.rect_stroke(ghost_rect, ghost_radius, ghost_stroke, egui::StrokeKind::Outside);
-                                                    
-                                                    let padding = 4.0;
-                                                    painter.text(
-                                                        ghost_rect.left_center() + egui::vec2(padding, 0.0),
-                                                        egui::Align2::LEFT_CENTER,
-                                                        account.full_name(),
-                                                        font_id.clone(),
-                                                        ghost_text_color,
-                                                    );
-                                                    
-                                                    painter.text(
-                                                        ghost_rect.right_center() - egui::vec2(padding, 0.0),
-                                                        egui::Align2::RIGHT_CENTER,
-                                                        &account.region,
-                                                        font_id.clone(),
-                                                        ghost_text_color,
-                                                    );
-                                                }
-                                                ui.ctx().request_repaint();
-                                            } else {
-                                                let mut visuals = ui.style().interact_selectable(&response, is_selected);
-                                                
-                                                if  current_drag_idx.is_some() {
-                                                     visuals.bg_fill = visuals.bg_fill.linear_multiply(0.8);
-                                                }
-                                                
-                                                if let Some(d_idx) = current_drag_idx {
-                                                    if d_idx != idx && response.hovered() {
-                                                         ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
-                                                    }
-                                                }
-
-                                                if is_selected || response.hovered() || response.has_focus() {
-                                                    ui.painter().rect(
-                                                        rect,
-                                                        visuals.corner_radius,
-                                                        visuals.bg_fill,
-                                                        visuals.bg_stroke,
-                                                        egui::StrokeKind::Outside,
-                                                    );
-                                                }
-                                                
-                                                let text_color = visuals.text_color();
-                                                let padding = 4.0;
-                                                
-                                                ui.painter().text(
-                                                    rect.left_center() + egui::vec2(padding, 0.0),
-                                                    egui::Align2::LEFT_CENTER,
-                                                    account.full_name(),
-                                                    font_id.clone(),
-                                                    text_color,
-                                                );
-                                                
-                                                ui.painter().text(
-                                                    rect.right_center() - egui::vec2(padding, 0.0),
-                                                    egui::Align2::RIGHT_CENTER,
-                                                    &account.region,
-                                                    font_id,
-                                                    text_color,
-                                                );
-                                            }
-                                        }
-                                        (swap_request, new_drag_idx, selected_account_idx)
-                                    });
+                    ui.add_space(((ui.available_width() - width) / 2.0).max(0.0));
+                    ui.vertical(|ui| {
+                        ui.set_width(width);
+                        let riot_path = self
+                            .riot_path_draft
+                            .get_or_insert_with(|| self.settings.riot_client_path.clone());
+                        let mut editor = SettingsEditor {
+                            riot_path,
+                            install_search: &mut self.riot_install_search,
+                        };
+                        edits = settings_page::show(ui, &mut self.settings, &self.settings_search, &mut editor);
+
+                        ui.add_space(15.0);
+                        ui.vertical_centered(|ui| {
+                            if self.riot_install_check.is_running() {
+                                ui.horizontal(|ui| {
+                                    ui.spinner();
+                                    ui.label(t!("install.checking"));
+                                });
+                                ui.ctx().request_repaint_after(std::time::Duration::from_millis(100));
+                            } else if ui.button(t!("settings.confirm")).clicked() {
+                                self.riot_install_check.start(self.riot_path_draft.clone().unwrap_or_default());
+                            }
+                        });
+                        ui.add_space(20.0);
+                    });
+                });
+            });
 
-                                if let Some((swap, drag_start, selection)) = combo_response.inner {
-                                    if let Some(idx) = drag_start {
-                                        self.dragged_account_idx = Some(idx);
-                                    }
+            if let Some((riot_path, result)) = self.riot_install_check.poll() {
+                match result {
+                    Err(e) => self.alert_message = Some(e),
+                    Ok(()) => {
+                        self.settings.riot_client_path = riot_path;
+                        if let Err(e) = settings::save_settings(&self.settings) {
+                            self.alert_message = Some(t!("error.save_settings", error = e));
+                        } else {
+                            self.settings_unsaved = false;
+                            self.riot_path_draft = None;
+                            self.current_view = View::Login;
+                        }
+                    }
+                }
+            }
+
+            let mut finished = false;
+            for edit in edits {
+                self.apply_setting_effect(ui.ctx(), edit.def.effect);
+                finished |= edit.finished;
+                self.settings_unsaved |= !edit.finished;
+            }
+            // A color picked by dragging has no end event of its own, so a pending change is
+            // also saved once nothing is held or focused any more.
+            let idle = ui.ctx().input(|i| !i.pointer.any_down()) && ui.ctx().memory(|m| m.focused().is_none());
+            if finished || (self.settings_unsaved && idle) {
+                if let Err(e) = settings::save_settings(&self.settings) {
+                    self.alert_message = Some(t!("error.save_settings", error = e));
+                }
+                self.settings_unsaved = false;
+            }
+        });
+    }
 
-                                    if let Some((from, to)) = swap {
-                                        self.saved_accounts.swap(from, to);
-                                        self.dragged_account_idx = Some(to);
-                                    }
+    fn apply_setting_effect(&mut self, ctx: &egui::Context, effect: Effect) {
+        match effect {
+            Effect::None => {}
+            Effect::Language => i18n::set_language(self.settings.ui_language),
+            Effect::Theme => theme::apply(ctx, self.settings.theme_mode, self.settings.accent_color),
+            Effect::Autostart => {
+                if let Err(e) = settings::set_autostart(self.settings.start_with_windows) {
+                    self.alert_message = Some(t!("error.autostart", error = e));
+                }
+            }
+            Effect::WatcherConfig => self.sync_watcher_config(),
+            Effect::DecayCheck => self.last_decay_check = None,
+        }
+    }
 
-                                    if let Some(idx) = selection {
-                                        if let Some(account) = self.saved_accounts.get(idx) {
-                                            self.selected_account_display = format!("{}           {}", account.full_name(), account.region);
-                                            self.username = account.username.clone();
-                                            self.password = account.password.clone();
-                                            self.region = account.region.clone();
-                                            self.in_game_name = account.in_game_name.clone();
-                                            self.custom_tag = account.custom_tag.clone();
-                                        }
-                                    }
-                                }
-                                
-                                if ui.input(|i| i.pointer.any_released()) {
-                                    if self.dragged_account_idx.is_some() {
-                                        self.dragged_account_idx = None;
-                                        self.drag_offset = None;
-                                        if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
-                                            self.alert_message = Some(format!("Error saving accounts: {}", e));
-                                        }
-                                    }
-                                }
+    fn render_login_view(&mut self, ctx: &egui::Context) {
+        if self.settings.minimalist_mode {
+            self.render_minimalist_view(ctx);
+            return;
+        }
 
-                                if let Some(region) = &display_region {
-                                    let rect = combo_response.response.rect;
-                                    let font_id = egui::TextStyle::Body.resolve(ui.style());
-                                    let visuals = ui.style().interact_selectable(&combo_response.response, false); 
-                                    let text_color = visuals.text_color();
-                                    
-                                    ui.painter().text(
-                                        rect.right_center() - egui::vec2(25.0, 0.0),
-                                        egui::Align2::RIGHT_CENTER,
-                                        region,
-                                        font_id,
-                                        text_color,
-                                    );
-                                }
+        egui::Area::new(egui::Id::new("settings_area"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("👥").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                        .on_hover_text(t!("manager.tooltip"))
+                        .clicked()
+                    {
+                        self.current_view = View::Accounts;
+                    }
+                    if ui.add(egui::Button::new("⚔").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                        .on_hover_text(t!("champ_select.tooltip"))
+                        .clicked()
+                    {
+                        self.open_champ_select_view();
+                    }
+                    if ui.add(egui::Button::new("📊").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                        .on_hover_text(t!("details.tooltip"))
+                        .clicked()
+                    {
+                        self.current_view = View::AccountDetails;
+                    }
+                    if ui.add(egui::Button::new("📜").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                        .on_hover_text(t!("history.tooltip"))
+                        .clicked()
+                    {
+                        let username = self
+                            .selected_account_index()
+                            .or(if self.saved_accounts.is_empty() { None } else { Some(0) })
+                            .map(|idx| self.saved_accounts[idx].username.clone());
+                        match username {
+                            Some(username) => self.open_match_history(&username),
+                            None => self.alert_message = Some(t!("error.save_account_first").to_owned()),
+                        }
+                    }
+                    if ui.add(egui::Button::new("📖").frame(false).min_size(egui::vec2(30.0, 30.0)))
+                        .on_hover_text(t!("presets.tooltip"))
+                        .clicked()
+                    {
+                        self.current_view = View::Presets;
+                    }
+                    if ui.add(egui::Button::new("⚙").frame(false).min_size(egui::vec2(30.0, 30.0))).clicked() {
+                        self.current_view = View::Settings;
+                    }
+                });
+            });
 
-                                if ui.button("📋").on_hover_text("Skopiuj nick").clicked() {
-                                    if self.selected_account_display != "Select an account..." {
-                                        if let Some(acc) = self.saved_accounts.iter().find(|acc| {
-                                            let label = format!("{}           {}", acc.full_name(), acc.region);
-                                            label == self.selected_account_display
-                                        }) {
-                                            ui.ctx().copy_text(acc.full_name());
-                                        }
-                                    }
-                                }
-                            });
-                            ui.end_row();
-                        });
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                
+                ui.vertical_centered(|ui| {
+                    ui.add_space(20.0);
+                    ui.heading(t!("login.title"));
+                    ui.add_space(30.0);
                 });
 
-                ui.add_space(10.0);
+                // The label moves above the picker once the row no longer fits next to it.
+                let label_text = t!("login.choose_account");
+                let label_width = ui.painter().layout_no_wrap(
+                    label_text.to_string(),
+                    egui::TextStyle::Body.resolve(ui.style()),
+                    ui.visuals().text_color(),
+                ).size().x + ui.spacing().item_spacing.x;
+                let buttons_width = 70.0;
+                let inline_label = ui.available_width() >= label_width + buttons_width + 220.0;
+                if !inline_label {
+                    ui.vertical_centered(|ui| ui.label(label_text));
+                }
 
                 ui.horizontal(|ui| {
-                     ui.add_space(final_margin + label_col_width + grid_spacing[0]);
-                     
-                     let btn_width = ((field_width + 8.0) - ui.spacing().item_spacing.x) / 2.0;
-
-                     if ui.add(egui::Button::new("Save Account").min_size(egui::vec2(btn_width, 0.0))).clicked() {
-                         if self.username.is_empty() {
-                             self.alert_message = Some("Username cannot be empty!".to_owned());
-                         } else {
-                             let new_account = Account::new(
-                                 self.username.clone(),
-                                 self.password.clone(),
-                                 self.region.clone(),
-                                 self.in_game_name.clone(),
-                                 self.custom_tag.clone(),
-                             );
- 
-                             self.saved_accounts.retain(|acc| acc.username != new_account.username);
-                             self.saved_accounts.push(new_account.clone());
- 
-                             if let Err(e) = credentials::save_accounts(&self.saved_accounts) {
-                                 self.alert_message = Some(format!("Error saving accounts: {}", e));
-                             } else {
-                                 self.selected_account_display = format!("{}           {}", new_account.full_name(), new_account.region);
-                                 self.alert_message = Some("Account saved successfully!".to_owned());
-                             }
-                         }
-                     }
+                    let fixed_width = buttons_width + if inline_label { label_width } else { 0.0 };
+                    let picker_width = (ui.available_width() - fixed_width - 20.0).clamp(120.0, 400.0);
+                    let row_width = fixed_width + picker_width;
+                    ui.add_space(((ui.available_width() - row_width) / 2.0).max(0.0));
+                    if inline_label {
+                        ui.label(label_text);
+                    }
+                    self.render_account_picker(ui, "account_combo", picker_width);
 
-                     if ui.add(egui::Button::new("Delete Account").min_size(egui::vec2(btn_width, 0.0))).clicked() {
-                        if self.selected_account_display != "Select an account..." {
-                            self.show_delete_confirmation = true;
-                        }
-                     }
-                });
+                    if ui.button("🏷").on_hover_text(t!("organize.tooltip")).clicked() {
+                        self.open_organize_window();
+                    }
 
-                ui.add_space(20.0);
+                    if ui.button("📋").on_hover_text(t!("login.copy_name")).clicked()
+                        && let Some(idx) = self.selected_account_index()
+                    {
+                        ui.ctx().copy_text(self.saved_accounts[idx].full_name());
+                    }
+                });
 
-                ui.horizontal(|ui| {
-                    let login_btn_width = 250.0;
-                    let kill_btn_width = 125.5; 
-                    let spacing = 20.0;
-                    let item_sp = ui.spacing().item_spacing.x;
-                    let total_width = login_btn_width + kill_btn_width + spacing + item_sp;
-                    
-                    let margin = (ui.available_width() - total_width) / 2.0;
-                    ui.add_space(margin.max(0.0));
-
-                    let btn_login = egui::Button::new("Login To League")
-                        .min_size(egui::vec2(login_btn_width, 50.0));
-                        
-                    if ui.add(btn_login).clicked() {
-                        if self.username.is_empty() {
-                            self.alert_message = Some("Choose an account!".to_owned());
-                        } else {
-                            match launcher::launch_and_login(self.username.clone(), self.password.clone(), self.settings.riot_client_path.clone()) {
-                                Ok(_) => {}, 
-                                Err(e) => self.alert_message = Some(format!("Error: {}", e)),
-                            }
+                if self.saved_accounts.is_empty() {
+                    ui.add_space(10.0);
+                    ui.vertical_centered(|ui| {
+                        ui.label(egui::RichText::new(t!("manager.empty")).weak());
+                        if ui.button(t!("manager.open")).clicked() {
+                            self.current_view = View::Accounts;
                         }
-                    }
+                    });
+                }
+                ui.add_space(20.0);
 
-                    ui.add_space(spacing);
+                // Login takes two thirds of the row, and the buttons stack once the window gets narrow.
+                let row_width = (ui.available_width() - 20.0).min(400.0);
+                let stacked = row_width < 300.0;
+                let spacing = 20.0;
+                let (login_btn_width, kill_btn_width) = if stacked {
+                    (row_width, row_width)
+                } else {
+                    let login = (row_width - spacing) * 2.0 / 3.0;
+                    (login, row_width - spacing - login)
+                };
+                let palette = Palette::of(ui.visuals());
+                let btn_login = egui::Button::new(t!("login.login"))
+                    .min_size(egui::vec2(login_btn_width, 50.0));
+                let kill_btn = egui::Button::new(
+                    egui::RichText::new(t!("login.kill")).color(palette.on_status)
+                )
+                .fill(palette.danger)
+                .min_size(egui::vec2(kill_btn_width, if stacked { 40.0 } else { 50.0 }));
+
+                let (login_clicked, kill_clicked) = if stacked {
+                    ui.vertical_centered(|ui| {
+                        let login = ui.add(btn_login).clicked();
+                        ui.add_space(10.0);
+                        (login, ui.add(kill_btn).clicked())
+                    })
+                    .inner
+                } else {
+                    ui.horizontal(|ui| {
+                        let item_sp = ui.spacing().item_spacing.x;
+                        ui.add_space(((ui.available_width() - row_width - item_sp) / 2.0).max(0.0));
+                        let login = ui.add(btn_login).clicked();
+                        ui.add_space(spacing - item_sp);
+                        (login, ui.add(kill_btn).clicked())
+                    })
+                    .inner
+                };
+                if login_clicked {
+                    self.start_login();
+                }
+                if kill_clicked {
+                    launcher::kill_league_processes();
+                    self.alert_message = Some(t!("status.killed").to_owned());
+                }
 
-                    let kill_btn = egui::Button::new(
-                        egui::RichText::new("Kill League Process").color(egui::Color32::WHITE)
-                    )
-                    .fill(egui::Color32::from_rgb(180, 40, 40))
-                    .min_size(egui::vec2(kill_btn_width, 50.0));
-                    if ui.add(kill_btn).clicked() {
-                        launcher::kill_league_processes();
-                        self.alert_message = Some("League processes killed.".to_owned());
-                    }
+                ui.vertical_centered(|ui| {
+                    self.render_launch_status(ui);
                 });
             });
         });
@@ -759,126 +2830,693 @@ impl RustyLeagueApp {
                 ui.heading("Rusty League");
                 ui.add_space(30.0);
 
-                let field_width = 300.0;
+                let field_width = (ui.available_width() - 40.0).clamp(120.0, 300.0);
 
                 ui.horizontal(|ui| {
                     let combo_width = field_width;
                     let avail = ui.available_width();
                     ui.add_space(((avail - combo_width) / 2.0).max(0.0));
 
-                    let (display_name, display_region) = {
-                        let parts: Vec<&str> = self.selected_account_display.split("           ").collect();
-                        let name = parts.first().map(|s| s.to_string()).unwrap_or_else(|| self.selected_account_display.clone());
-                        let region = parts.get(1).map(|s| s.to_string());
-                        (name, region)
-                    };
-
-                    let combo_response = egui::ComboBox::from_id_salt("minimalist_account_combo")
-                        .selected_text(&display_name)
-                        .width(combo_width)
-                        .height(250.0)
-                        .show_ui(ui, |ui| {
-                            let mut selected_idx = None;
-                            for (idx, account) in self.saved_accounts.iter().enumerate() {
-                                let label = format!("{}           {}", account.full_name(), account.region);
-                                let is_selected = self.selected_account_display == label;
-
-                                let font_id = egui::TextStyle::Body.resolve(ui.style());
-                                let row_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
-
-                                let (rect, response) = ui.allocate_exact_size(
-                                    egui::vec2(ui.available_width(), row_height),
-                                    egui::Sense::click()
-                                );
-
-                                if response.clicked() {
-                                    selected_idx = Some(idx);
-                                }
-
-                                let visuals = ui.style().interact_selectable(&response, is_selected);
-                                if is_selected || response.hovered() || response.has_focus() {
-                                    ui.painter().rect(
-                                        rect,
-                                        visuals.corner_radius,
-                                        visuals.bg_fill,
-                                        visuals.bg_stroke,
-                                        egui::StrokeKind::Outside,
-                                    );
-                                }
-
-                                let text_color = visuals.text_color();
-                                let padding = 4.0;
-                                ui.painter().text(
-                                    rect.left_center() + egui::vec2(padding, 0.0),
-                                    egui::Align2::LEFT_CENTER,
-                                    account.full_name(),
-                                    font_id.clone(),
-                                    text_color,
-                                );
-                                ui.painter().text(
-                                    rect.right_center() - egui::vec2(padding, 0.0),
-                                    egui::Align2::RIGHT_CENTER,
-                                    &account.region,
-                                    font_id,
-                                    text_color,
-                                );
-                            }
-                            selected_idx
-                        });
-
-                    if let Some(Some(idx)) = combo_response.inner {
-                        if let Some(account) = self.saved_accounts.get(idx) {
-                            self.selected_account_display = format!("{}           {}", account.full_name(), account.region);
-                            self.username = account.username.clone();
-                            self.password = account.password.clone();
-                            self.region = account.region.clone();
-                            self.in_game_name = account.in_game_name.clone();
-                            self.custom_tag = account.custom_tag.clone();
-                        }
-                    }
-
-                    if let Some(region) = &display_region {
-                        let rect = combo_response.response.rect;
-                        let font_id = egui::TextStyle::Body.resolve(ui.style());
-                        let visuals = ui.style().interact_selectable(&combo_response.response, false);
-                        let text_color = visuals.text_color();
-                        ui.painter().text(
-                            rect.right_center() - egui::vec2(25.0, 0.0),
-                            egui::Align2::RIGHT_CENTER,
-                            region,
-                            font_id,
-                            text_color,
-                        );
-                    }
+                    self.render_account_picker(ui, "minimalist_account_combo", combo_width);
                 });
 
                 ui.add_space(20.0);
 
-                let login_btn = egui::Button::new("Login To League")
+                let login_btn = egui::Button::new(t!("login.login"))
                     .min_size(egui::vec2(field_width, 50.0));
                 if ui.add(login_btn).clicked() {
-                    if self.username.is_empty() {
-                        self.alert_message = Some("Choose an account!".to_owned());
-                    } else {
-                        match launcher::launch_and_login(self.username.clone(), self.password.clone(), self.settings.riot_client_path.clone()) {
-                            Ok(_) => {},
-                            Err(e) => self.alert_message = Some(format!("Error: {}", e)),
-                        }
-                    }
+                    self.start_login();
                 }
 
                 ui.add_space(10.0);
 
+                let palette = Palette::of(ui.visuals());
                 let kill_btn = egui::Button::new(
-                    egui::RichText::new("Kill League Process").color(egui::Color32::WHITE)
+                    egui::RichText::new(t!("login.kill")).color(palette.on_status)
                 )
-                .fill(egui::Color32::from_rgb(180, 40, 40))
+                .fill(palette.danger)
                 .min_size(egui::vec2(field_width, 40.0));
                 if ui.add(kill_btn).clicked() {
                     launcher::kill_league_processes();
-                    self.alert_message = Some("League processes killed.".to_owned());
+                    self.alert_message = Some(t!("status.killed").to_owned());
                 }
+
+                self.render_launch_status(ui);
+            });
+        });
+    }
+}
+
+// Riot ID, region, username, group, last used and rank.
+fn manager_columns() -> [(&'static str, f32); 6] {
+    [
+        (t!("column.riot_id"), 150.0),
+        (t!("column.region"), 45.0),
+        (t!("column.username"), 110.0),
+        (t!("column.group"), 80.0),
+        (t!("column.last_used"), 75.0),
+        (t!("column.rank"), 80.0),
+    ]
+}
+
+fn account_count(count: usize) -> String {
+    match i18n::plural(count as u64) {
+        "one" => t!("count.accounts.one").to_owned(),
+        "few" => t!("count.accounts.few", count = count),
+        _ => t!("count.accounts.many", count = count),
+    }
+}
+
+fn table_cell<R>(ui: &mut egui::Ui, width: f32, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R {
+    ui.allocate_ui_with_layout(
+        egui::vec2(width, ui.spacing().interact_size.y),
+        egui::Layout::left_to_right(egui::Align::Center),
+        |ui| {
+            ui.set_width(width);
+            add_contents(ui)
+        },
+    )
+    .inner
+}
+
+// Returns whether Save and Cancel were clicked.
+fn account_form(
+    ui: &mut egui::Ui,
+    draft: &mut AccountDraft,
+    show_password: &mut bool,
+    groups: &[String],
+) -> (bool, bool) {
+    let field_width = 220.0;
+    let mut save = false;
+    let mut cancel = false;
+
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        let title = if draft.original_username.is_some() { t!("form.edit") } else { t!("form.new") };
+        ui.label(egui::RichText::new(title).strong());
+        ui.add_space(4.0);
+
+        egui::Grid::new("account_form_grid")
+            .num_columns(2)
+            .spacing([10.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(t!("form.username"));
+                ui.add(egui::TextEdit::singleline(&mut draft.username).desired_width(field_width));
+                ui.end_row();
+
+                ui.label(t!("form.password"));
+                let password_response = ui.add(
+                    egui::TextEdit::singleline(&mut draft.password)
+                        .password(!*show_password)
+                        .desired_width(field_width),
+                );
+                let eye_icon = if *show_password { "🚫" } else { "👁" };
+                ui.scope_builder(egui::UiBuilder::new().max_rect(password_response.rect), |ui| {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.add_space(8.0);
+                        if ui.add(egui::Button::new(eye_icon).frame(false).small()).on_hover_text(t!("form.show_password")).clicked() {
+                            *show_password = !*show_password;
+                        }
+                    });
+                });
+                ui.end_row();
+
+                ui.label(t!("form.in_game_name"));
+                ui.horizontal(|ui| {
+                    let tag_width = 45.0;
+                    let name_width = field_width - tag_width - ui.spacing().item_spacing.x * 2.0 - 16.0;
+                    // Pasting "Name#TAG" fills both fields.
+                    if ui.add(egui::TextEdit::singleline(&mut draft.in_game_name).desired_width(name_width)).changed()
+                        && let Some((name, tag)) = draft.in_game_name.clone().split_once('#')
+                    {
+                        draft.in_game_name = name.to_owned();
+                        draft.custom_tag = tag.to_owned();
+                    }
+                    ui.label("#");
+                    ui.add(egui::TextEdit::singleline(&mut draft.custom_tag).desired_width(tag_width))
+                        .on_hover_text(t!("form.tag_hint"));
+                    if draft.custom_tag.chars().count() > 5 {
+                        draft.custom_tag = draft.custom_tag.chars().take(5).collect();
+                    }
+                });
+                ui.end_row();
+
+                ui.label(t!("form.region"));
+                egui::ComboBox::from_id_salt("account_form_region")
+                    .selected_text(&draft.region)
+                    .width(field_width)
+                    .show_ui(ui, |ui| {
+                        for region in ["EUNE", "EUW", "NA"] {
+                            ui.selectable_value(&mut draft.region, region.to_owned(), region);
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(t!("form.language"));
+                let language_label = riot_client::LOCALES
+                    .iter()
+                    .find(|(code, _)| *code == draft.language)
+                    .map(|(_, name)| *name)
+                    .unwrap_or(t!("form.dont_change"));
+                egui::ComboBox::from_id_salt("account_form_language")
+                    .selected_text(language_label)
+                    .width(field_width)
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut draft.language, String::new(), t!("form.dont_change"));
+                        for (code, name) in riot_client::LOCALES {
+                            ui.selectable_value(&mut draft.language, code.to_string(), name);
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(t!("field.group"));
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut draft.group)
+                            .hint_text(t!("field.group_hint"))
+                            .desired_width(field_width - 30.0),
+                    );
+                    if !groups.is_empty() {
+                        ui.menu_button("▾", |ui| {
+                            for group in groups {
+                                if ui.button(group).clicked() {
+                                    draft.group = group.clone();
+                                    ui.close();
+                                }
+                            }
+                        });
+                    }
+                });
+                ui.end_row();
+
+                ui.label(t!("form.ready_check"));
+                ui.checkbox(&mut draft.auto_accept, t!("form.auto_accept"));
+                ui.end_row();
             });
+
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            save = ui.button(t!("common.save")).clicked();
+            cancel = ui.button(t!("common.cancel")).clicked();
+        });
+    });
+
+    (save, cancel)
+}
+
+// Draws the settings page's custom rows on top of the app's own state.
+struct SettingsEditor<'a> {
+    riot_path: &'a mut String,
+    install_search: &'a mut riot_client::InstallSearch,
+}
+
+impl settings_page::CustomEditor for SettingsEditor<'_> {
+    fn show(&mut self, ui: &mut egui::Ui, _settings: &mut Settings, custom: settings_page::Custom) -> bool {
+        match custom {
+            settings_page::Custom::RiotClientPath => {
+                // Only the draft changes here; Confirm validates and saves it.
+                riot_path_editor(ui, self.riot_path, self.install_search);
+                false
+            }
+        }
+    }
+
+    fn is_default(&self, _settings: &Settings, custom: settings_page::Custom) -> bool {
+        match custom {
+            settings_page::Custom::RiotClientPath => self.riot_path.is_empty(),
+        }
+    }
+
+    fn reset(&mut self, _settings: &mut Settings, custom: settings_page::Custom) {
+        match custom {
+            settings_page::Custom::RiotClientPath => self.riot_path.clear(),
+        }
+    }
+}
+
+// Path field with a file picker, plus the installations found on this machine.
+fn riot_path_editor(ui: &mut egui::Ui, riot_path: &mut String, install_search: &mut riot_client::InstallSearch) {
+    ui.horizontal(|ui| {
+        let button_width = 30.0;
+        let spacing = ui.spacing().item_spacing.x;
+        let text_edit_width = ui.available_width() - (spacing + button_width) * 2.0;
+        ui.add(egui::TextEdit::singleline(riot_path).desired_width(text_edit_width));
+        if ui.button("📂").clicked()
+            && let Some(path) = rfd::FileDialog::new()
+                .add_filter(t!("settings.executables"), &["exe"])
+                .pick_file()
+        {
+            *riot_path = path.to_string_lossy().into_owned();
+        }
+        if ui.button("🔍").on_hover_text(t!("settings.detect")).clicked() {
+            install_search.restart();
+        }
+    });
+
+    let Some(detected) = install_search.poll() else {
+        ui.horizontal(|ui| {
+            ui.spinner();
+            ui.label(egui::RichText::new(t!("settings.detecting")).small().weak());
         });
+        ui.ctx().request_repaint_after(std::time::Duration::from_millis(200));
+        return;
+    };
+    if detected.is_empty() {
+        ui.label(egui::RichText::new(t!("settings.none_detected")).small().weak());
+    } else {
+        ui.label(egui::RichText::new(t!("settings.detected")).small().weak());
+        for path in detected.iter() {
+            let is_current = path.eq_ignore_ascii_case(riot_path);
+            if ui.selectable_label(is_current, egui::RichText::new(path).small()).clicked() {
+                *riot_path = path.clone();
+            }
+        }
+    }
+}
+
+fn queue_rank(ranked: &lcu::RankedStats, queue_type: &str) -> Option<QueueRank> {
+    let queue = ranked.queue(queue_type)?;
+    if queue.tier.is_empty() || queue.tier == "NONE" || queue.tier == "UNRANKED" {
+        return None;
+    }
+    Some(QueueRank {
+        tier: queue.tier.clone(),
+        division: queue.division.clone(),
+        league_points: queue.league_points,
+        wins: queue.wins,
+        losses: queue.losses,
+    })
+}
+
+fn tier_color(tier: &str) -> egui::Color32 {
+    match tier {
+        "IRON" => egui::Color32::from_rgb(95, 85, 80),
+        "BRONZE" => egui::Color32::from_rgb(140, 90, 60),
+        "SILVER" => egui::Color32::from_rgb(150, 160, 170),
+        "GOLD" => egui::Color32::from_rgb(200, 160, 60),
+        "PLATINUM" => egui::Color32::from_rgb(60, 160, 150),
+        "EMERALD" => egui::Color32::from_rgb(40, 170, 90),
+        "DIAMOND" => egui::Color32::from_rgb(90, 120, 220),
+        "MASTER" => egui::Color32::from_rgb(160, 80, 200),
+        "GRANDMASTER" => egui::Color32::from_rgb(200, 60, 60),
+        "CHALLENGER" => egui::Color32::from_rgb(230, 200, 90),
+        _ => egui::Color32::from_gray(90),
+    }
+}
+
+fn format_age(timestamp: u64) -> String {
+    let secs = credentials::unix_now().saturating_sub(timestamp);
+    match secs {
+        0..=59 => t!("age.just_now").to_owned(),
+        60..=3599 => t!("age.minutes", count = secs / 60),
+        3600..=86399 => t!("age.hours", count = secs / 3600),
+        86400..=172799 => t!("age.yesterday").to_owned(),
+        _ => t!("age.days", count = secs / 86400),
+    }
+}
+
+// 1234567 -> "1 234 567"
+fn format_amount(value: u64) -> String {
+    let digits = value.to_string();
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            result.push(' ');
+        }
+        result.push(digit);
+    }
+    result
+}
+
+fn section_header(ui: &mut egui::Ui, title: &str) {
+    ui.label(egui::RichText::new(title).small().weak());
+}
+
+// Returns true when clicked, to collapse or expand the group.
+fn group_header(ui: &mut egui::Ui, name: &str, count: usize, collapsed: bool) -> bool {
+    let arrow = if collapsed { "▶" } else { "▼" };
+    let title = if name.is_empty() { t!("picker.ungrouped") } else { name };
+    let text = egui::RichText::new(format!("{} {} ({})", arrow, title, count)).small().strong();
+    ui.add(egui::Button::new(text).frame(false)).clicked()
+}
+
+fn account_matches_search(account: &Account, search: &str) -> bool {
+    search.trim().is_empty()
+        || [
+            account.full_name(),
+            account.username.clone(),
+            account.region.clone(),
+            account.group.clone(),
+            account.notes.clone(),
+        ]
+        .iter()
+        .chain(&account.labels)
+        .any(|field| !search_match_ranges(field, search).is_empty())
+}
+
+// Byte ranges of case-insensitive occurrences of `search` in `text`.
+fn search_match_ranges(text: &str, search: &str) -> Vec<std::ops::Range<usize>> {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = search.trim().chars().map(lower).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i + needle.len() <= chars.len() {
+        let is_match = chars[i..i + needle.len()]
+            .iter()
+            .zip(&needle)
+            .all(|((_, c), n)| lower(*c) == *n);
+        if is_match {
+            let end = chars.get(i + needle.len()).map(|(pos, _)| *pos).unwrap_or(text.len());
+            ranges.push(chars[i].0..end);
+            i += needle.len();
+        } else {
+            i += 1;
+        }
+    }
+    ranges
+}
+
+#[allow(clippy::too_many_arguments)]
+fn paint_highlighted_text(
+    painter: &egui::Painter,
+    pos: egui::Pos2,
+    align: egui::Align2,
+    text: &str,
+    search: &str,
+    font_id: egui::FontId,
+    color: egui::Color32,
+    highlight: egui::Color32,
+) -> egui::Rect {
+    let plain = egui::TextFormat::simple(font_id.clone(), color);
+    let marked = egui::TextFormat { background: highlight, ..egui::TextFormat::simple(font_id, color) };
+
+    let mut job = egui::text::LayoutJob::default();
+    let mut last = 0;
+    for range in search_match_ranges(text, search) {
+        job.append(&text[last..range.start], 0.0, plain.clone());
+        job.append(&text[range.clone()], 0.0, marked.clone());
+        last = range.end;
+    }
+    job.append(&text[last..], 0.0, plain);
+
+    let galley = painter.layout_job(job);
+    let rect = align.anchor_size(pos, galley.size());
+    painter.galley(rect.min, galley, color);
+    rect
+}
+
+fn account_row_height(ui: &egui::Ui, account: &Account) -> f32 {
+    let body = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+    if account.stats.is_some() || account.last_used_at.is_some() {
+        body + ui.text_style_height(&egui::TextStyle::Small) + 4.0
+    } else {
+        body
+    }
+}
+
+fn paint_rank_emblem(painter: &egui::Painter, center: egui::Pos2, size: f32, rank: Option<&QueueRank>) {
+    let color = rank.map(|r| tier_color(&r.tier)).unwrap_or(egui::Color32::from_gray(90));
+    let half = size / 2.0;
+    let points = vec![
+        center + egui::vec2(0.0, -half),
+        center + egui::vec2(half, 0.0),
+        center + egui::vec2(0.0, half),
+        center + egui::vec2(-half, 0.0),
+    ];
+    painter.add(egui::Shape::convex_polygon(points, color, egui::Stroke::new(1.0, color.gamma_multiply(1.4))));
+}
+
+// Name and region on the first line; rank, level and age of the data on a second line once known.
+fn paint_account_row(
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    account: &Account,
+    text_color: egui::Color32,
+    decay_warning_days: u32,
+    search: &str,
+) {
+    let padding = 4.0;
+    let font_id = egui::TextStyle::Body.resolve(ui.style());
+    let body_height = ui.text_style_height(&egui::TextStyle::Body) + 4.0;
+    let top = egui::Rect::from_min_size(rect.min, egui::vec2(rect.width(), body_height));
+    let palette = Palette::of(ui.visuals());
+    let highlight = palette.accent;
+
+    let mut name_left = top.left() + padding;
+    if let Some([r, g, b]) = account.color {
+        let radius = font_id.size / 4.0;
+        painter.circle_filled(egui::pos2(name_left + radius, top.center().y), radius, egui::Color32::from_rgb(r, g, b));
+        name_left += radius * 2.0 + padding;
+    }
+
+    let name_rect = paint_highlighted_text(
+        painter,
+        egui::pos2(name_left, top.center().y),
+        egui::Align2::LEFT_CENTER,
+        &account.full_name(),
+        search,
+        font_id.clone(),
+        text_color,
+        highlight,
+    );
+
+    let mut label_left = name_rect.right() + padding * 2.0;
+    for label in &account.labels {
+        let galley = painter.layout_no_wrap(
+            label.clone(),
+            egui::TextStyle::Small.resolve(ui.style()),
+            text_color.gamma_multiply(0.8),
+        );
+        let chip = egui::Rect::from_min_size(
+            egui::pos2(label_left, top.center().y - galley.size().y / 2.0 - 1.0),
+            galley.size() + egui::vec2(6.0, 2.0),
+        );
+        painter.rect_stroke(chip, 3.0, egui::Stroke::new(1.0, text_color.gamma_multiply(0.4)), egui::StrokeKind::Inside);
+        painter.galley(chip.min + egui::vec2(3.0, 1.0), galley, text_color);
+        label_left = chip.right() + padding;
+    }
+    let region_rect = paint_highlighted_text(
+        painter,
+        top.right_center() - egui::vec2(padding, 0.0),
+        egui::Align2::RIGHT_CENTER,
+        &account.region,
+        search,
+        font_id,
+        text_color,
+        highlight,
+    );
+
+    if let Some(restriction) = restrictions::most_severe(&account.restrictions, credentials::unix_now()) {
+        let color = if restriction.is_blocking() { palette.danger } else { palette.warning };
+        let badge = painter.layout_no_wrap(
+            format!("⛔ {}", restriction.badge()),
+            egui::TextStyle::Small.resolve(ui.style()),
+            palette.on_status,
+        );
+        let badge_rect = egui::Rect::from_min_size(
+            egui::pos2(
+                region_rect.left() - padding * 2.0 - badge.size().x - 6.0,
+                top.center().y - badge.size().y / 2.0 - 1.0,
+            ),
+            badge.size() + egui::vec2(6.0, 2.0),
+        );
+        painter.rect_filled(badge_rect, 3.0, color);
+        painter.galley(badge_rect.min + egui::vec2(3.0, 1.0), badge, palette.on_status);
+    }
+
+    let small_font = egui::TextStyle::Small.resolve(ui.style());
+    let bottom_center_y = (top.bottom() + rect.bottom()) / 2.0;
+    let last_used = account.last_used_at.map(|at| {
+        let outcome = account.last_outcome.map(|o| format!("{} ", o.icon())).unwrap_or_default();
+        t!("picker.last_used", outcome = outcome, age = format_age(at))
+    });
+
+    let Some(stats) = &account.stats else {
+        if let Some(last_used) = last_used {
+            painter.text(
+                egui::pos2(rect.right() - padding, bottom_center_y),
+                egui::Align2::RIGHT_CENTER,
+                last_used,
+                small_font,
+                text_color.gamma_multiply(0.6),
+            );
+        }
+        return;
+    };
+
+    let emblem_size = small_font.size;
+    let emblem_center = egui::pos2(rect.left() + padding + emblem_size / 2.0, bottom_center_y);
+    paint_rank_emblem(painter, emblem_center, emblem_size, stats.solo_duo.as_ref());
+
+    let solo = stats.solo_duo.as_ref().map(QueueRank::short_label).unwrap_or_else(|| t!("common.unranked").to_owned());
+    let mut details = t!("card.solo", rank = solo);
+    if let Some(flex) = &stats.flex {
+        details.push_str(&format!(" · {}", t!("card.flex", rank = flex.short_label())));
+    }
+    details.push_str(&format!(" · {}", t!("card.level", level = stats.summoner_level)));
+
+    painter.text(
+        egui::pos2(emblem_center.x + emblem_size / 2.0 + padding, bottom_center_y),
+        egui::Align2::LEFT_CENTER,
+        details,
+        small_font.clone(),
+        text_color.gamma_multiply(0.8),
+    );
+    let decay_status = decay::status(stats, credentials::unix_now());
+    let warning_color = decay_status.as_ref().and_then(|status| {
+        if status.days_left <= 0.0 {
+            Some(palette.danger)
+        } else if status.is_at_risk(decay_warning_days) {
+            Some(palette.warning)
+        } else {
+            None
+        }
+    });
+
+    if let Some(color) = warning_color {
+        let stripe = egui::Rect::from_min_size(rect.min, egui::vec2(3.0, rect.height()));
+        painter.rect_filled(stripe, 1.0, color);
+    }
+
+    let right_text = match (&decay_status, last_used) {
+        (Some(status), _) => status.label(),
+        (None, Some(last_used)) => last_used,
+        (None, None) => format_age(stats.updated_at),
+    };
+    let right_color = warning_color.unwrap_or(text_color.gamma_multiply(0.6));
+
+    painter.text(
+        egui::pos2(rect.right() - padding, bottom_center_y),
+        egui::Align2::RIGHT_CENTER,
+        right_text,
+        small_font,
+        right_color,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_ignores_case() {
+        assert_eq!(search_match_ranges("FakerFan", "fa"), vec![0..2, 5..7]);
+        assert_eq!(search_match_ranges("abc", "  B "), vec![1..2]);
+        assert!(search_match_ranges("abc", "").is_empty());
+        assert!(search_match_ranges("ab", "abc").is_empty());
+    }
+
+    #[test]
+    fn search_returns_byte_ranges_for_unicode() {
+        // "Ł" and "ó" take two bytes each, so char and byte positions differ.
+        let text = "Łódź ŁÓDŹ";
+        let ranges = search_match_ranges(text, "łódź");
+        assert_eq!(ranges, vec![0..7, 8..15]);
+        assert!(ranges.iter().all(|range| &text[range.clone()] == "Łódź" || &text[range.clone()] == "ŁÓDŹ"));
+
+        assert_eq!(search_match_ranges("żółw", "ÓŁ"), vec![2..6]);
+    }
+
+    #[test]
+    fn search_matches_do_not_overlap() {
+        assert_eq!(search_match_ranges("aaaa", "aa"), vec![0..2, 2..4]);
+    }
+
+    fn account(username: &str) -> Account {
+        Account::new(username.into(), "secret".into(), "EUNE".into(), username.into(), String::new(), String::new())
+    }
+
+    fn usernames(accounts: &[Account]) -> Vec<&str> {
+        accounts.iter().map(|acc| acc.username.as_str()).collect()
+    }
+
+    #[test]
+    fn drop_position_accounts_for_the_removed_row() {
+        // Dropped in front of the row below itself, or onto itself: nothing moves.
+        assert_eq!(drop_position(1, 1, 4), None);
+        assert_eq!(drop_position(1, 2, 4), None);
+        assert_eq!(drop_position(1, 3, 4), Some(2));
+        assert_eq!(drop_position(3, 0, 4), Some(0));
+        // Past the last row means "at the end".
+        assert_eq!(drop_position(0, 4, 4), Some(3));
+        assert_eq!(drop_position(3, 9, 4), None);
+        assert_eq!(drop_position(4, 0, 4), None);
+    }
+
+    #[test]
+    fn undo_added_and_moved() {
+        let mut accounts = vec![account("a"), account("b"), account("c")];
+        let mut trash = Vec::new();
+
+        AccountChange::Added("b".into()).revert(&mut accounts, &mut trash);
+        assert_eq!(usernames(&accounts), ["a", "c"]);
+
+        accounts.push(account("d"));
+        let account_d = accounts.remove(2);
+        accounts.insert(0, account_d);
+        AccountChange::Moved { from: 2, to: 0 }.revert(&mut accounts, &mut trash);
+        assert_eq!(usernames(&accounts), ["a", "c", "d"]);
+    }
+
+    #[test]
+    fn undo_deleted_takes_accounts_out_of_the_trash() {
+        let mut accounts = vec![account("b")];
+        let mut trash = vec![
+            TrashedAccount { account: account("a"), deleted_at: 10 },
+            TrashedAccount { account: account("c"), deleted_at: 10 },
+        ];
+
+        AccountChange::Deleted(vec![(0, account("a")), (2, account("c"))]).revert(&mut accounts, &mut trash);
+        assert_eq!(usernames(&accounts), ["a", "b", "c"]);
+        assert!(trash.is_empty());
+    }
+
+    #[test]
+    fn undo_deleted_skips_usernames_saved_again() {
+        let mut accounts = vec![account("a")];
+        let mut trash = vec![TrashedAccount { account: account("a"), deleted_at: 10 }];
+
+        AccountChange::Deleted(vec![(0, account("a"))]).revert(&mut accounts, &mut trash);
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(trash.len(), 1);
+    }
+
+    #[test]
+    fn undo_edited_keeps_data_collected_since() {
+        let mut accounts = vec![account("old")];
+        let before = EditedFields::of(&accounts[0]);
+        accounts[0].username = "new".into();
+        accounts[0].group = "Smurfs".into();
+        accounts[0].auto_accept = true;
+        accounts[0].use_count = 3;
+        accounts[0].last_used_at = Some(100);
+        accounts[0].stats = Some(AccountStats { summoner_level: 30, ..AccountStats::default() });
+
+        AccountChange::Edited(vec![("new".into(), before)]).revert(&mut accounts, &mut Vec::new());
+        let account = &accounts[0];
+        assert_eq!(account.username, "old");
+        assert_eq!(account.group, "");
+        assert!(!account.auto_accept);
+        assert_eq!(account.use_count, 3);
+        assert_eq!(account.last_used_at, Some(100));
+        assert_eq!(account.stats.as_ref().map(|stats| stats.summoner_level), Some(30));
+    }
+
+    #[test]
+    fn undo_restored_puts_the_account_back_in_the_trash() {
+        let entry = TrashedAccount { account: account("a"), deleted_at: 42 };
+        let mut accounts = vec![account("b"), entry.account.clone()];
+        let mut trash = Vec::new();
+
+        AccountChange::Restored(Box::new(entry)).revert(&mut accounts, &mut trash);
+        assert_eq!(usernames(&accounts), ["b"]);
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].account.username, "a");
+        assert_eq!(trash[0].deleted_at, 42);
     }
 }
@@ -0,0 +1,269 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use crate::i18n::t;
+use crate::lcu::{ChampSelectAction, ChampSelectSession, ChampionSummary};
+
+pub const ANY_ROLE: &str = "any";
+
+// LCU `assignedPosition` values, with "any" as the fallback list for every role and blind pick.
+pub const ROLES: [&str; 6] = [ANY_ROLE, "top", "jungle", "middle", "bottom", "utility"];
+
+pub fn role_label(role: &str) -> &'static str {
+    match role {
+        "top" => t!("role.top"),
+        "jungle" => t!("role.jungle"),
+        "middle" => t!("role.middle"),
+        "bottom" => t!("role.bottom"),
+        "utility" => t!("role.utility"),
+        _ => t!("role.any"),
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ChampSelectPreferences {
+    #[serde(default)]
+    pub enabled: bool,
+    // Off means "hover only": the assistant never locks in a pick or a ban.
+    #[serde(default)]
+    pub lock_in: bool,
+    #[serde(default)]
+    pub picks: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub bans: HashMap<String, Vec<String>>,
+}
+
+impl ChampSelectPreferences {
+    fn candidates<'a>(lists: &'a HashMap<String, Vec<String>>, role: &str) -> impl Iterator<Item = &'a String> {
+        let role_list = if role.is_empty() || role == ANY_ROLE { None } else { lists.get(role) };
+        role_list
+            .into_iter()
+            .flatten()
+            .chain(lists.get(ANY_ROLE).into_iter().flatten())
+    }
+}
+
+pub struct ChampionIndex {
+    ids: HashMap<String, i64>,
+    names: HashMap<i64, String>,
+}
+
+impl ChampionIndex {
+    pub fn new(summaries: &[ChampionSummary]) -> Self {
+        let mut ids = HashMap::new();
+        let mut names = HashMap::new();
+        for champion in summaries.iter().filter(|c| c.id > 0) {
+            ids.insert(normalize_name(&champion.name), champion.id);
+            ids.insert(normalize_name(&champion.alias), champion.id);
+            names.insert(champion.id, champion.name.clone());
+        }
+        Self { ids, names }
+    }
+
+    pub fn id(&self, name: &str) -> Option<i64> {
+        self.ids.get(&normalize_name(name)).copied()
+    }
+
+    pub fn name(&self, id: i64) -> String {
+        self.names.get(&id).cloned().unwrap_or_else(|| format!("#{}", id))
+    }
+}
+
+// "Kai'Sa", "kaisa" and "KaiSa" all resolve to the same champion.
+fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+pub enum Step {
+    Hover { action_id: i64, champion_id: i64, ban: bool },
+    Lock { action_id: i64, champion_id: i64, ban: bool },
+}
+
+// Remembers what it already did in the current champion select, so a champion the
+// user hovers by hand is not overridden again.
+#[derive(Default)]
+pub struct Assistant {
+    hovered: HashSet<i64>,
+    locked: HashSet<i64>,
+}
+
+impl Assistant {
+    pub fn next_step(
+        &mut self,
+        session: &ChampSelectSession,
+        prefs: &ChampSelectPreferences,
+        index: &ChampionIndex,
+        pickable: &[i64],
+        bannable: &[i64],
+    ) -> Option<Step> {
+        let role = session.local_player().map(|p| p.assigned_position.as_str()).unwrap_or("");
+
+        let pending: Vec<&ChampSelectAction> = session
+            .actions
+            .iter()
+            .flatten()
+            .filter(|a| a.actor_cell_id == session.local_player_cell_id && !a.completed)
+            .collect();
+        // The current turn first, otherwise the upcoming pick so its intent shows during planning.
+        let action = pending
+            .iter()
+            .find(|a| a.is_in_progress)
+            .or_else(|| pending.iter().find(|a| a.action_type == "pick"))?;
+
+        match action.action_type.as_str() {
+            "ban" if action.is_in_progress => {
+                let teammate_choices: HashSet<i64> = session
+                    .my_team
+                    .iter()
+                    .flat_map(|p| [p.champion_id, p.champion_pick_intent])
+                    .collect();
+                let preferred: Vec<i64> = ChampSelectPreferences::candidates(&prefs.bans, role)
+                    .filter_map(|name| index.id(name))
+                    .collect();
+                let best = preferred
+                    .iter()
+                    .copied()
+                    .find(|id| bannable.contains(id) && !teammate_choices.contains(id));
+                self.step_for(action, best, &preferred, bannable, prefs.lock_in, true)
+            }
+            "pick" => {
+                let preferred: Vec<i64> = ChampSelectPreferences::candidates(&prefs.picks, role)
+                    .filter_map(|name| index.id(name))
+                    .collect();
+                let best = preferred.iter().copied().find(|id| pickable.contains(id));
+                // Before our turn only the pick intent is shown, so never lock early.
+                let lock = prefs.lock_in && action.is_in_progress;
+                self.step_for(action, best, &preferred, pickable, lock, false)
+            }
+            _ => None,
+        }
+    }
+
+    fn step_for(
+        &mut self,
+        action: &ChampSelectAction,
+        best: Option<i64>,
+        preferred: &[i64],
+        available: &[i64],
+        lock: bool,
+        ban: bool,
+    ) -> Option<Step> {
+        let current_available = action.champion_id > 0 && available.contains(&action.champion_id);
+        // Only champions from the user's lists get locked; anything else they hovered is theirs to confirm.
+        let current_preferred = best == Some(action.champion_id) || preferred.contains(&action.champion_id);
+
+        let needs_hover = !self.hovered.contains(&action.id) || !current_available;
+        if needs_hover
+            && let Some(champion_id) = best
+            && champion_id != action.champion_id
+        {
+            self.hovered.insert(action.id);
+            return Some(Step::Hover { action_id: action.id, champion_id, ban });
+        }
+
+        if lock && current_available && current_preferred && !self.locked.contains(&action.id) {
+            self.locked.insert(action.id);
+            return Some(Step::Lock { action_id: action.id, champion_id: action.champion_id, ban });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lcu::ChampSelectPlayer;
+
+    const AHRI: i64 = 103;
+    const KAISA: i64 = 145;
+    const ZED: i64 = 238;
+    const ALL: [i64; 3] = [AHRI, KAISA, ZED];
+
+    fn index() -> ChampionIndex {
+        let champion = |id: i64, name: &str, alias: &str| ChampionSummary { id, name: name.to_owned(), alias: alias.to_owned() };
+        ChampionIndex::new(&[champion(AHRI, "Ahri", "Ahri"), champion(KAISA, "Kai'Sa", "Kaisa"), champion(ZED, "Zed", "Zed")])
+    }
+
+    fn prefs(picks: &[&str], bans: &[&str]) -> ChampSelectPreferences {
+        let list = |names: &[&str]| HashMap::from([("middle".to_owned(), names.iter().map(|n| n.to_string()).collect())]);
+        ChampSelectPreferences { enabled: true, lock_in: true, picks: list(picks), bans: list(bans) }
+    }
+
+    fn session(action_type: &str, champion_id: i64, in_progress: bool) -> ChampSelectSession {
+        ChampSelectSession {
+            local_player_cell_id: 1,
+            my_team: vec![ChampSelectPlayer { cell_id: 1, assigned_position: "middle".to_owned(), ..Default::default() }],
+            actions: vec![vec![ChampSelectAction {
+                id: 7,
+                actor_cell_id: 1,
+                champion_id,
+                completed: false,
+                is_in_progress: in_progress,
+                action_type: action_type.to_owned(),
+            }]],
+        }
+    }
+
+    #[test]
+    fn hovers_then_locks_the_preferred_pick() {
+        let mut assistant = Assistant::default();
+        let prefs = prefs(&["Ahri"], &[]);
+
+        let step = assistant.next_step(&session("pick", 0, true), &prefs, &index(), &ALL, &ALL);
+        assert!(matches!(step, Some(Step::Hover { action_id: 7, champion_id: AHRI, ban: false })));
+
+        let step = assistant.next_step(&session("pick", AHRI, true), &prefs, &index(), &ALL, &ALL);
+        assert!(matches!(step, Some(Step::Lock { action_id: 7, champion_id: AHRI, ban: false })));
+
+        assert!(assistant.next_step(&session("pick", AHRI, true), &prefs, &index(), &ALL, &ALL).is_none());
+    }
+
+    #[test]
+    fn never_locks_a_champion_hovered_by_hand() {
+        let mut assistant = Assistant::default();
+        let prefs = prefs(&["Ahri"], &[]);
+
+        // Nothing from the list is available, so the user's own hover stays untouched.
+        let step = assistant.next_step(&session("pick", ZED, true), &prefs, &index(), &[ZED], &ALL);
+        assert!(step.is_none());
+    }
+
+    #[test]
+    fn locks_a_listed_champion_hovered_by_hand() {
+        let mut assistant = Assistant::default();
+        let prefs = prefs(&["Ahri", "Kai'Sa"], &[]);
+
+        assistant.next_step(&session("pick", 0, true), &prefs, &index(), &ALL, &ALL);
+        let step = assistant.next_step(&session("pick", KAISA, true), &prefs, &index(), &ALL, &ALL);
+        assert!(matches!(step, Some(Step::Lock { champion_id: KAISA, .. })));
+    }
+
+    #[test]
+    fn only_hovers_before_the_turn() {
+        let mut assistant = Assistant::default();
+        let prefs = prefs(&["Ahri"], &[]);
+
+        let step = assistant.next_step(&session("pick", 0, false), &prefs, &index(), &ALL, &ALL);
+        assert!(matches!(step, Some(Step::Hover { champion_id: AHRI, .. })));
+        assert!(assistant.next_step(&session("pick", AHRI, false), &prefs, &index(), &ALL, &ALL).is_none());
+    }
+
+    #[test]
+    fn skips_bans_on_teammate_picks() {
+        let mut assistant = Assistant::default();
+        let prefs = prefs(&[], &["Zed", "Ahri"]);
+        let mut session = session("ban", 0, true);
+        session.my_team.push(ChampSelectPlayer { cell_id: 2, champion_pick_intent: ZED, ..Default::default() });
+
+        let step = assistant.next_step(&session, &prefs, &index(), &ALL, &ALL);
+        assert!(matches!(step, Some(Step::Hover { champion_id: AHRI, ban: true, .. })));
+    }
+
+    #[test]
+    fn normalizes_champion_names() {
+        assert_eq!(normalize_name("Kai'Sa"), "kaisa");
+        assert_eq!(normalize_name("Nunu & Willump"), "nunuwillump");
+        assert_eq!(normalize_name("Dr. Mundo"), "drmundo");
+        assert_eq!(index().id("KAISA"), Some(KAISA));
+    }
+}
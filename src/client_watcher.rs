@@ -0,0 +1,272 @@
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::{thread, time};
+use crate::champ_select::{self, ChampSelectPreferences, ChampionIndex};
+use crate::i18n::t;
+use crate::lcu::{self, ChampSelectSession, GameflowPhase, LcuClient, Summoner};
+use crate::match_history::{self, StoredMatch};
+use crate::presets::{self, LoadoutPreset};
+use crate::riot_client;
+
+const POLL_INTERVAL: time::Duration = time::Duration::from_millis(500);
+const RECONNECT_INTERVAL: time::Duration = time::Duration::from_secs(5);
+const INSTALL_DIRS_REFRESH: time::Duration = time::Duration::from_secs(60);
+
+#[derive(Clone, Default)]
+pub struct WatcherConfig {
+    pub auto_accept: bool,
+    pub accept_delay: time::Duration,
+    pub champ_select: Option<ChampSelectPreferences>,
+    pub presets: Vec<LoadoutPreset>,
+}
+
+pub enum WatcherEvent {
+    Connected(Summoner),
+    Disconnected,
+    ReadyCheckAccepted,
+    ChampSelect(String),
+    MatchesLoaded(Vec<StoredMatch>),
+}
+
+// Keeps an eye on the running League Client through its local API, independently of how
+// the user signed in. The thread stops once the watcher is dropped.
+pub struct ClientWatcher {
+    config: Arc<Mutex<WatcherConfig>>,
+    events: mpsc::Receiver<WatcherEvent>,
+    running: Arc<AtomicBool>,
+}
+
+impl ClientWatcher {
+    pub fn spawn() -> Self {
+        let config = Arc::new(Mutex::new(WatcherConfig::default()));
+        let running = Arc::new(AtomicBool::new(true));
+        let (tx, rx) = mpsc::channel();
+
+        let thread_config = Arc::clone(&config);
+        let thread_running = Arc::clone(&running);
+        thread::spawn(move || run(thread_config, thread_running, tx));
+
+        Self { config, events: rx, running }
+    }
+
+    pub fn set_config(&self, config: WatcherConfig) {
+        if let Ok(mut current) = self.config.lock() {
+            *current = config;
+        }
+    }
+
+    pub fn try_recv(&self) -> Option<WatcherEvent> {
+        self.events.try_recv().ok()
+    }
+}
+
+impl Drop for ClientWatcher {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+    }
+}
+
+fn run(config: Arc<Mutex<WatcherConfig>>, running: Arc<AtomicBool>, tx: mpsc::Sender<WatcherEvent>) {
+    let mut install_dirs: Vec<PathBuf> = Vec::new();
+    let mut dirs_refreshed: Option<time::Instant> = None;
+
+    while running.load(Ordering::Relaxed) {
+        if dirs_refreshed.is_none_or(|at| at.elapsed() > INSTALL_DIRS_REFRESH) {
+            install_dirs = riot_client::league_install_dirs();
+            dirs_refreshed = Some(time::Instant::now());
+        }
+
+        let Some(lockfile) = lcu::find_lockfile_in(&install_dirs) else {
+            thread::sleep(RECONNECT_INTERVAL);
+            continue;
+        };
+        let client = LcuClient::from_lockfile(&lockfile);
+
+        let summoner = match client.current_summoner() {
+            Ok(summoner) if !summoner.puuid.is_empty() => summoner,
+            _ => {
+                thread::sleep(RECONNECT_INTERVAL);
+                continue;
+            }
+        };
+
+        if tx.send(WatcherEvent::Connected(summoner)).is_err() {
+            return;
+        }
+
+        watch_session(&client, &config, &running, &tx);
+
+        if tx.send(WatcherEvent::Disconnected).is_err() {
+            return;
+        }
+        // The lockfile may be stale right after the client closes.
+        thread::sleep(RECONNECT_INTERVAL);
+    }
+}
+
+fn watch_session(
+    client: &LcuClient,
+    config: &Mutex<WatcherConfig>,
+    running: &AtomicBool,
+    tx: &mpsc::Sender<WatcherEvent>,
+) {
+    let mut ready_check_since: Option<time::Instant> = None;
+    let mut accepted = false;
+    let mut champion_index: Option<ChampionIndex> = None;
+    let mut assistant = champ_select::Assistant::default();
+    let mut loadout_applied_for: Option<i64> = None;
+    let mut previous_phase: Option<GameflowPhase> = None;
+
+    while running.load(Ordering::Relaxed) {
+        let phase = match client.gameflow_phase() {
+            Ok(phase) => phase,
+            Err(_) => return,
+        };
+        let config = config.lock().map(|c| c.clone()).unwrap_or_default();
+
+        if phase == GameflowPhase::ReadyCheck {
+            let since = *ready_check_since.get_or_insert_with(time::Instant::now);
+            if config.auto_accept
+                && !accepted
+                && since.elapsed() >= config.accept_delay
+                && client.accept_ready_check().is_ok()
+            {
+                accepted = true;
+                let _ = tx.send(WatcherEvent::ReadyCheckAccepted);
+            }
+        } else {
+            ready_check_since = None;
+            accepted = false;
+        }
+
+        if phase == GameflowPhase::ChampSelect {
+            let prefs = config.champ_select.as_ref().filter(|prefs| prefs.enabled);
+            if (prefs.is_some() || !config.presets.is_empty()) && champion_index.is_none() {
+                champion_index = client.champion_summaries().ok().map(|s| ChampionIndex::new(&s));
+            }
+            if let Some(index) = &champion_index
+                && let Ok(session) = client.champ_select_session()
+            {
+                let messages = [
+                    prefs.and_then(|prefs| run_champ_select_step(client, &session, prefs, index, &mut assistant)),
+                    apply_loadout(client, &session, &config.presets, index, &mut loadout_applied_for),
+                ];
+                for message in messages.into_iter().flatten() {
+                    let _ = tx.send(WatcherEvent::ChampSelect(message));
+                }
+            }
+        } else {
+            assistant = champ_select::Assistant::default();
+            loadout_applied_for = None;
+        }
+
+        // The finished game shows up in the match history once the post-game screens are left.
+        if previous_phase.is_some_and(is_post_game) && !is_post_game(phase) {
+            if champion_index.is_none() {
+                champion_index = client.champion_summaries().ok().map(|s| ChampionIndex::new(&s));
+            }
+            if let Ok(games) = client.recent_matches(5) {
+                let matches = match_history::from_games(&games, champion_index.as_ref());
+                let _ = tx.send(WatcherEvent::MatchesLoaded(matches));
+            }
+        }
+        previous_phase = Some(phase);
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn is_post_game(phase: GameflowPhase) -> bool {
+    matches!(
+        phase,
+        GameflowPhase::WaitingForStats | GameflowPhase::PreEndOfGame | GameflowPhase::EndOfGame
+    )
+}
+
+fn run_champ_select_step(
+    client: &LcuClient,
+    session: &ChampSelectSession,
+    prefs: &ChampSelectPreferences,
+    index: &ChampionIndex,
+    assistant: &mut champ_select::Assistant,
+) -> Option<String> {
+    let pickable = client.pickable_champion_ids().unwrap_or_default();
+    let bannable = client.bannable_champion_ids().unwrap_or_default();
+
+    match assistant.next_step(session, prefs, index, &pickable, &bannable)? {
+        champ_select::Step::Hover { action_id, champion_id, ban } => {
+            client.hover_champion(action_id, champion_id).ok()?;
+            let champion = index.name(champion_id);
+            Some(if ban {
+                t!("champ_select.hovering_ban", champion = champion)
+            } else {
+                t!("champ_select.hovering", champion = champion)
+            })
+        }
+        champ_select::Step::Lock { action_id, champion_id, ban } => {
+            client.complete_action(action_id).ok()?;
+            let champion = index.name(champion_id);
+            Some(if ban {
+                t!("champ_select.banned", champion = champion)
+            } else {
+                t!("champ_select.locked", champion = champion)
+            })
+        }
+    }
+}
+
+// Applies the preset for our champion once it is locked in, once per champion select.
+fn apply_loadout(
+    client: &LcuClient,
+    session: &ChampSelectSession,
+    presets: &[LoadoutPreset],
+    index: &ChampionIndex,
+    applied_for: &mut Option<i64>,
+) -> Option<String> {
+    let champion_id = session
+        .actions
+        .iter()
+        .flatten()
+        .find(|a| a.actor_cell_id == session.local_player_cell_id && a.action_type == "pick" && a.completed)
+        .map(|a| a.champion_id)
+        .filter(|id| *id > 0)?;
+    if *applied_for == Some(champion_id) {
+        return None;
+    }
+    *applied_for = Some(champion_id);
+
+    let role = session.local_player().map(|p| p.assigned_position.as_str()).unwrap_or("");
+    let preset = presets::find(presets, index, champion_id, role)?;
+
+    let mut errors = Vec::new();
+    if let Some(runes) = &preset.runes
+        && let Err(e) = apply_rune_page(client, &preset.name, runes)
+    {
+        errors.push(e);
+    }
+    if preset.has_spells()
+        && let Err(e) = client.set_summoner_spells(preset.spell1_id, preset.spell2_id)
+    {
+        errors.push(e);
+    }
+
+    if errors.is_empty() {
+        Some(t!("presets.applied", name = preset.name, champion = index.name(champion_id)))
+    } else {
+        Some(t!("presets.failed", name = preset.name, error = errors.join(" ")))
+    }
+}
+
+// Replaces the page created last time, so presets never use up the player's own rune pages.
+fn apply_rune_page(client: &LcuClient, preset_name: &str, runes: &presets::RunePreset) -> Result<(), String> {
+    for page in client.rune_pages()? {
+        if page.is_deletable && page.name.starts_with(presets::RUNE_PAGE_PREFIX) {
+            client.delete_rune_page(page.id)?;
+        }
+    }
+
+    let name = format!("{}: {}", presets::RUNE_PAGE_PREFIX, preset_name);
+    client.create_rune_page(&name, runes.primary_style_id, runes.sub_style_id, &runes.selected_perk_ids)
+}
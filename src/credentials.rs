@@ -1,6 +1,124 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
 use directories::ProjectDirs;
+use crate::champ_select::ChampSelectPreferences;
+use crate::decay::DecayBank;
+use crate::i18n::t;
+use crate::settings::AccountSort;
+use crate::restrictions::Restriction;
+use crate::wallet::WalletSnapshot;
+
+pub const SOLO_DUO_QUEUE: &str = "RANKED_SOLO_5x5";
+pub const FLEX_QUEUE: &str = "RANKED_FLEX_SR";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct QueueRank {
+    pub tier: String,
+    pub division: String,
+    pub league_points: i32,
+    #[serde(default)]
+    pub wins: u32,
+    #[serde(default)]
+    pub losses: u32,
+}
+
+impl QueueRank {
+    pub fn is_apex(&self) -> bool {
+        matches!(self.tier.as_str(), "MASTER" | "GRANDMASTER" | "CHALLENGER")
+    }
+
+    pub fn tier_short(&self) -> &'static str {
+        match self.tier.as_str() {
+            "IRON" => "I",
+            "BRONZE" => "B",
+            "SILVER" => "S",
+            "GOLD" => "G",
+            "PLATINUM" => "P",
+            "EMERALD" => "E",
+            "DIAMOND" => "D",
+            "MASTER" => "M",
+            "GRANDMASTER" => "GM",
+            "CHALLENGER" => "C",
+            _ => "?",
+        }
+    }
+
+    // Orders ranks across tiers: tier, then division, then LP.
+    pub fn score(&self) -> i64 {
+        const TIERS: [&str; 10] = [
+            "IRON", "BRONZE", "SILVER", "GOLD", "PLATINUM", "EMERALD", "DIAMOND", "MASTER", "GRANDMASTER", "CHALLENGER",
+        ];
+        let tier = TIERS.iter().position(|t| *t == self.tier).map(|t| t as i64).unwrap_or(-1);
+        let division = match self.division.as_str() {
+            "I" => 3,
+            "II" => 2,
+            "III" => 1,
+            _ => 0,
+        };
+        (tier * 4 + division) * 10_000 + self.league_points as i64
+    }
+
+    // e.g. "D2 57 LP" or "M 230 LP"
+    pub fn short_label(&self) -> String {
+        let division = match self.division.as_str() {
+            "I" => "1",
+            "II" => "2",
+            "III" => "3",
+            "IV" => "4",
+            _ => "",
+        };
+        if self.is_apex() || division.is_empty() {
+            format!("{} {} LP", self.tier_short(), self.league_points)
+        } else {
+            format!("{}{} {} LP", self.tier_short(), division, self.league_points)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AccountStats {
+    #[serde(default)]
+    pub puuid: String,
+    pub summoner_level: u32,
+    #[serde(default)]
+    pub solo_duo: Option<QueueRank>,
+    #[serde(default)]
+    pub flex: Option<QueueRank>,
+    pub updated_at: u64,
+    #[serde(default)]
+    pub last_solo_game_at: Option<u64>,
+    #[serde(default)]
+    pub decay_bank: Option<DecayBank>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LaunchOutcome {
+    SignedIn,
+    LeagueNotStarted,
+    Restricted,
+    Failed,
+}
+
+impl LaunchOutcome {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            LaunchOutcome::SignedIn => "✔",
+            LaunchOutcome::LeagueNotStarted => "⚠",
+            LaunchOutcome::Restricted => "⛔",
+            LaunchOutcome::Failed => "✖",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LaunchOutcome::SignedIn => t!("outcome.signed_in"),
+            LaunchOutcome::LeagueNotStarted => t!("outcome.league_not_started"),
+            LaunchOutcome::Restricted => t!("outcome.restricted"),
+            LaunchOutcome::Failed => t!("outcome.failed"),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct Account {
@@ -9,6 +127,32 @@ pub struct Account {
     pub region: String,
     pub in_game_name: String,
     pub custom_tag: String,
+    #[serde(default)]
+    pub language: String,
+    #[serde(default)]
+    pub stats: Option<AccountStats>,
+    #[serde(default)]
+    pub auto_accept: bool,
+    #[serde(default)]
+    pub champ_select: ChampSelectPreferences,
+    #[serde(default)]
+    pub restrictions: Vec<Restriction>,
+    #[serde(default)]
+    pub wallet: Option<WalletSnapshot>,
+    #[serde(default)]
+    pub group: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub color: Option<[u8; 3]>,
+    #[serde(default)]
+    pub last_used_at: Option<u64>,
+    #[serde(default)]
+    pub use_count: u32,
+    #[serde(default)]
+    pub last_outcome: Option<LaunchOutcome>,
 }
 
 impl Account {
@@ -18,6 +162,7 @@ impl Account {
         region: String,
         in_game_name: String,
         custom_tag: String,
+        language: String,
     ) -> Self {
         Self {
             username,
@@ -25,9 +170,27 @@ impl Account {
             region,
             in_game_name,
             custom_tag,
+            language,
+            stats: None,
+            auto_accept: false,
+            champ_select: ChampSelectPreferences::default(),
+            restrictions: Vec::new(),
+            wallet: None,
+            group: String::new(),
+            labels: Vec::new(),
+            notes: String::new(),
+            color: None,
+            last_used_at: None,
+            use_count: 0,
+            last_outcome: None,
         }
     }
 
+    // Empty for accounts outside any group.
+    pub fn group_name(&self) -> &str {
+        self.group.trim()
+    }
+
     pub fn full_name(&self) -> String {
         if self.custom_tag.trim().is_empty() {
             format!("{}#{}", self.in_game_name, self.region)
@@ -37,6 +200,66 @@ impl Account {
     }
 }
 
+pub fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+// Sorted, without duplicates and without the empty "no group". Groups differing only in
+// case are one group.
+pub fn group_names(accounts: &[Account]) -> Vec<String> {
+    let mut groups: Vec<String> = accounts
+        .iter()
+        .map(|acc| acc.group_name().to_owned())
+        .filter(|group| !group.is_empty())
+        .collect();
+    groups.sort_by_key(|group| group.to_lowercase());
+    groups.dedup_by(|a, b| a.to_lowercase() == b.to_lowercase());
+    groups
+}
+
+// The group as typed for `username`, spelled like an existing group of another account
+// when it only differs in case.
+pub fn normalize_group(accounts: &[Account], username: &str, group: &str) -> String {
+    let group = group.trim();
+    let lower = group.to_lowercase();
+    accounts
+        .iter()
+        .filter(|acc| acc.username != username)
+        .map(Account::group_name)
+        .find(|existing| existing.to_lowercase() == lower)
+        .unwrap_or(group)
+        .to_owned()
+}
+
+// Sorts account indices for display. The sort is stable, so ties keep the manual order.
+pub fn sort_indices(accounts: &[Account], indices: &mut [usize], sort: AccountSort) {
+    let solo_score = |acc: &Account| acc.stats.as_ref().and_then(|s| s.solo_duo.as_ref()).map(QueueRank::score);
+    match sort {
+        AccountSort::Manual => {}
+        AccountSort::Name => indices.sort_by_key(|&i| accounts[i].full_name().to_lowercase()),
+        AccountSort::Region => indices.sort_by_key(|&i| accounts[i].region.to_lowercase()),
+        AccountSort::LastUsed => indices.sort_by_key(|&i| std::cmp::Reverse(accounts[i].last_used_at)),
+        AccountSort::MostUsed => indices.sort_by_key(|&i| std::cmp::Reverse(accounts[i].use_count)),
+        AccountSort::Rank => indices.sort_by_key(|&i| std::cmp::Reverse(solo_score(&accounts[i]))),
+    }
+}
+
+// Updates the login details of the account with the same username, keeping everything
+// recorded about it from previous logins. Unknown usernames are appended.
+pub fn upsert_account(accounts: &mut Vec<Account>, account: Account) {
+    match accounts.iter_mut().find(|acc| acc.username == account.username) {
+        Some(existing) => {
+            existing.password = account.password;
+            existing.region = account.region;
+            existing.in_game_name = account.in_game_name;
+            existing.custom_tag = account.custom_tag;
+            existing.language = account.language;
+            existing.auto_accept = account.auto_accept;
+        }
+        None => accounts.push(account),
+    }
+}
+
 pub fn save_accounts(accounts: &[Account]) -> std::io::Result<()> {
     if let Some(proj_dirs) = ProjectDirs::from("pl", "Rusty Credentials", "") {
         let config_dir = proj_dirs.config_dir();
@@ -68,3 +291,29 @@ pub fn load_accounts() -> Vec<Account> {
         Vec::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(username: &str, group: &str) -> Account {
+        let mut account = Account::new(username.into(), String::new(), "EUNE".into(), username.into(), String::new(), String::new());
+        account.group = group.into();
+        account
+    }
+
+    #[test]
+    fn group_names_ignore_case_and_blanks() {
+        let accounts = [account("a", "Main"), account("b", "main "), account("c", ""), account("d", "Alts")];
+        assert_eq!(group_names(&accounts), vec!["Alts", "Main"]);
+    }
+
+    #[test]
+    fn groups_take_the_existing_spelling() {
+        let accounts = [account("a", "Smurfs"), account("b", "")];
+        assert_eq!(normalize_group(&accounts, "b", " smurfs "), "Smurfs");
+        assert_eq!(normalize_group(&accounts, "b", "New"), "New");
+        // The only account in a group can change its spelling.
+        assert_eq!(normalize_group(&accounts, "a", "SMURFS"), "SMURFS");
+    }
+}
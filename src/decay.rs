@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use crate::credentials::{AccountStats, QueueRank};
+use crate::i18n::t;
+
+const SECONDS_PER_DAY: f32 = 86_400.0;
+
+// Ranked solo/duo queue id in match history.
+pub const SOLO_DUO_QUEUE_ID: i32 = 420;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct DecayBank {
+    pub days: f32,
+    pub recorded_at: u64,
+}
+
+pub struct DecayRules {
+    pub days_per_game: f32,
+    pub max_bank_days: f32,
+}
+
+pub struct DecayStatus {
+    pub days_left: f32,
+    pub banked_games: u32,
+}
+
+// Only Diamond and above decay. Diamond banks 7 days per game up to 28,
+// Master and above bank 1 day per game up to 14.
+pub fn rules_for(rank: &QueueRank) -> Option<DecayRules> {
+    match rank.tier.as_str() {
+        "DIAMOND" => Some(DecayRules { days_per_game: 7.0, max_bank_days: 28.0 }),
+        "MASTER" | "GRANDMASTER" | "CHALLENGER" => Some(DecayRules { days_per_game: 1.0, max_bank_days: 14.0 }),
+        _ => None,
+    }
+}
+
+fn days_between(from: u64, to: u64) -> f32 {
+    to.saturating_sub(from) as f32 / SECONDS_PER_DAY
+}
+
+// Called after `stats.solo_duo` has been refreshed. The bank drains one day per day
+// and refills by the ranked games played since the previous snapshot. Without a previous
+// snapshot the bank is assumed to have been full at the last solo/duo game, and without
+// that either it is unknown.
+pub fn update_bank(stats: &mut AccountStats, previous_solo: Option<&QueueRank>, now: u64) {
+    let Some(solo) = &stats.solo_duo else {
+        stats.decay_bank = None;
+        return;
+    };
+    let Some(rules) = rules_for(solo) else {
+        stats.decay_bank = None;
+        return;
+    };
+
+    let days = match (stats.decay_bank, previous_solo) {
+        (Some(bank), Some(previous)) => {
+            let new_games = (solo.wins + solo.losses).saturating_sub(previous.wins + previous.losses);
+            (bank.days - days_between(bank.recorded_at, now)).max(0.0) + new_games as f32 * rules.days_per_game
+        }
+        _ => match stats.last_solo_game_at {
+            Some(last_game) => rules.max_bank_days - days_between(last_game, now),
+            None => {
+                stats.decay_bank = None;
+                return;
+            }
+        },
+    };
+
+    stats.decay_bank = Some(DecayBank {
+        days: days.clamp(0.0, rules.max_bank_days),
+        recorded_at: now,
+    });
+}
+
+pub fn status(stats: &AccountStats, now: u64) -> Option<DecayStatus> {
+    let rules = rules_for(stats.solo_duo.as_ref()?)?;
+    let bank = stats.decay_bank?;
+
+    let days_left = bank.days - days_between(bank.recorded_at, now);
+    let banked_games = (days_left.max(0.0) / rules.days_per_game).ceil() as u32;
+
+    Some(DecayStatus { days_left, banked_games })
+}
+
+impl DecayStatus {
+    pub fn is_at_risk(&self, warning_days: u32) -> bool {
+        self.days_left <= warning_days as f32
+    }
+
+    pub fn label(&self) -> String {
+        if self.days_left <= 0.0 {
+            t!("decay.decaying").to_owned()
+        } else {
+            t!("decay.in", days = format!("{:.0}", self.days_left.ceil()), banked = self.banked_games)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+    const DAY: u64 = 86_400;
+
+    fn rank(tier: &str, games: u32) -> QueueRank {
+        QueueRank { tier: tier.to_owned(), division: "I".to_owned(), league_points: 0, wins: games, losses: 0 }
+    }
+
+    fn stats(tier: &str, games: u32) -> AccountStats {
+        AccountStats { solo_duo: Some(rank(tier, games)), ..AccountStats::default() }
+    }
+
+    #[test]
+    fn unknown_bank_without_history() {
+        let mut stats = stats("DIAMOND", 10);
+        update_bank(&mut stats, None, NOW);
+        assert_eq!(stats.decay_bank, None);
+        assert!(status(&stats, NOW).is_none());
+    }
+
+    #[test]
+    fn full_bank_at_the_last_game() {
+        let mut stats = stats("DIAMOND", 10);
+        stats.last_solo_game_at = Some(NOW - 3 * DAY);
+        update_bank(&mut stats, None, NOW);
+        assert_eq!(stats.decay_bank, Some(DecayBank { days: 25.0, recorded_at: NOW }));
+    }
+
+    #[test]
+    fn bank_drains_and_refills() {
+        let mut stats = stats("MASTER", 12);
+        stats.decay_bank = Some(DecayBank { days: 5.0, recorded_at: NOW - 2 * DAY });
+        update_bank(&mut stats, Some(&rank("MASTER", 10)), NOW);
+        assert_eq!(stats.decay_bank.map(|bank| bank.days), Some(5.0));
+
+        stats.solo_duo = Some(rank("MASTER", 40));
+        update_bank(&mut stats, Some(&rank("MASTER", 12)), NOW);
+        assert_eq!(stats.decay_bank.map(|bank| bank.days), Some(14.0));
+    }
+
+    #[test]
+    fn no_bank_below_diamond() {
+        let mut stats = stats("EMERALD", 10);
+        stats.decay_bank = Some(DecayBank { days: 5.0, recorded_at: NOW });
+        update_bank(&mut stats, None, NOW);
+        assert_eq!(stats.decay_bank, None);
+    }
+
+    #[test]
+    fn status_counts_banked_games() {
+        let mut stats = stats("DIAMOND", 10);
+        stats.decay_bank = Some(DecayBank { days: 20.0, recorded_at: NOW - 4 * DAY });
+
+        let banked = status(&stats, NOW).unwrap();
+        assert_eq!(banked.days_left, 16.0);
+        assert_eq!(banked.banked_games, 3);
+        assert!(!banked.is_at_risk(3));
+
+        let decaying = status(&stats, NOW + 20 * DAY).unwrap();
+        assert_eq!(decaying.days_left, -4.0);
+        assert_eq!(decaying.banked_games, 0);
+        assert!(decaying.is_at_risk(3));
+    }
+}
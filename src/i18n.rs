@@ -0,0 +1,913 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Polish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Polish];
+
+    // Always shown in the language itself, so it can be found from any UI language.
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Polish => "Polski",
+        }
+    }
+
+    fn entries(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => ENGLISH,
+            Language::Polish => POLISH,
+        }
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(language: Language) {
+    CURRENT.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match CURRENT.load(Ordering::Relaxed) {
+        1 => Language::Polish,
+        _ => Language::English,
+    }
+}
+
+fn catalog(language: Language) -> &'static HashMap<&'static str, &'static str> {
+    static CATALOGS: OnceLock<[HashMap<&'static str, &'static str>; 2]> = OnceLock::new();
+    let catalogs = CATALOGS.get_or_init(|| Language::ALL.map(|language| language.entries().iter().copied().collect()));
+    &catalogs[language as usize]
+}
+
+// Falls back to English, then to the key itself, so a missing entry never hides a label.
+pub fn tr(key: &'static str) -> &'static str {
+    catalog(language())
+        .get(key)
+        .or_else(|| catalog(Language::English).get(key))
+        .copied()
+        .unwrap_or(key)
+}
+
+// Fills `{name}` placeholders in the translated text.
+pub fn tr_args(key: &'static str, args: &[(&str, String)]) -> String {
+    args.iter()
+        .fold(tr(key).to_owned(), |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+}
+
+// Plural form for counted nouns in the current language.
+pub fn plural(count: u64) -> &'static str {
+    plural_form(language(), count)
+}
+
+// "one", "few" or "many" (English only uses "one" and "many").
+pub fn plural_form(language: Language, count: u64) -> &'static str {
+    match language {
+        Language::English if count == 1 => "one",
+        Language::English => "many",
+        Language::Polish => match (count, count % 10, count % 100) {
+            (1, _, _) => "one",
+            (_, 2..=4, tens) if !(12..=14).contains(&tens) => "few",
+            _ => "many",
+        },
+    }
+}
+
+// t!("key") looks up a fixed text, t!("key", name = value) also fills its placeholders.
+macro_rules! t {
+    ($key:literal) => {
+        $crate::i18n::tr($key)
+    };
+    ($key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::tr_args($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+pub(crate) use t;
+
+const ENGLISH: &[(&str, &str)] = &[
+    ("common.ok", "OK"),
+    ("common.save", "Save"),
+    ("common.cancel", "Cancel"),
+    ("common.edit", "Edit"),
+    ("common.delete", "Delete"),
+    ("common.yes", "Yes"),
+    ("common.no", "No"),
+    ("common.back_without_saving", "Back without saving"),
+    ("common.unranked", "Unranked"),
+    ("common.none", "None"),
+
+    ("alert.title", "Info"),
+
+    ("error.generic", "Error: {error}"),
+    ("error.save_accounts", "Error saving accounts: {error}"),
+    ("error.save_settings", "Error saving settings: {error}"),
+    ("error.save_trash", "Error saving the trash: {error}"),
+    ("error.move_history", "Could not move the match history of {name}: {error}"),
+    ("error.save_history", "Error saving the match history: {error}"),
+    ("error.notification", "Could not show a notification: {error}"),
+    ("error.client_settings_apply", "Could not set the client region and language in {error}"),
+    ("error.client_settings_restore", "Could not restore the client settings in {error}"),
+    ("error.autostart", "Autostart error: {error}"),
+    ("error.choose_account", "Choose an account!"),
+    ("error.account_missing", "Account no longer exists."),
+    ("error.username_empty", "Username cannot be empty!"),
+    ("error.username_taken", "An account with the username {username} already exists!"),
+    ("error.save_account_first", "Save an account first!"),
+    ("error.champion_required", "Enter a champion name!"),
+    ("error.preset_empty", "Import a rune page or choose two summoner spells!"),
+
+    ("install.path_empty", "Path cannot be empty!"),
+    ("install.missing", "{path} does not exist. The Riot Client may have been moved or uninstalled."),
+    ("install.folder", "{path} is a folder, not {exe}."),
+    ("install.wrong_file", "Selected file is {file}, expected {exe}."),
+    ("install.unreadable", "Cannot read {path}: {error}"),
+    ("install.not_executable", "{path} is not a valid Windows executable."),
+    ("install.wrong_publisher", "{path} is published by \"{company}\", not Riot Games."),
+    ("install.incomplete", "{exe} was found, but the rest of the Riot Client is missing next to it. Reinstall the Riot Client or pick another installation."),
+    ("install.not_found", "Riot Client installation not found. Set the path to RiotClientServices.exe in Settings."),
+    ("install.unavailable", "Riot Client is not available: {error} Check the path in Settings."),
+    ("install.check_failed", "Could not check the Riot Client installation."),
+    ("install.checking", "Checking the Riot Client..."),
+
+    ("card.solo", "Solo {rank}"),
+    ("card.flex", "Flex {rank}"),
+    ("card.level", "Lv {level}"),
+
+    ("restriction.banned", "Banned"),
+    ("restriction.suspended", "Suspended"),
+    ("restriction.leaver_buster", "Leaver Buster"),
+    ("restriction.ranked", "Ranked restricted"),
+    ("restriction.chat", "Chat restricted"),
+    ("restriction.until", "{name} until {date}"),
+    ("restriction.games.one", "{name} (1 game)"),
+    ("restriction.games.few", "{name} ({games} games)"),
+    ("restriction.games.many", "{name} ({games} games)"),
+
+    ("decay.decaying", "decaying"),
+    ("decay.in", "decay in {days}d ({banked} banked)"),
+
+    ("lcu.not_running", "League Client is not running (lockfile not found)."),
+    ("lcu.request_failed", "{method} {endpoint} failed: {error}"),
+
+    ("picker.placeholder", "Select an account..."),
+    ("picker.search_hint", "🔍 Search accounts..."),
+    ("picker.change_order", "Change the order"),
+    ("picker.group", "▤ Group"),
+    ("picker.all", "All"),
+    ("picker.recently_used", "Recently used"),
+    ("picker.all_accounts", "All accounts"),
+    ("picker.no_matches", "No matching accounts."),
+    ("picker.ungrouped", "Ungrouped"),
+    ("picker.last_used", "{outcome}last used {age}"),
+
+    ("status.starting", "Starting Riot Client..."),
+    ("status.login_screen", "Login screen found, typing credentials..."),
+    ("status.login_screen_timeout", "Login screen not found in time, typing credentials anyway..."),
+    ("status.submitted_launching", "Credentials submitted, launching League..."),
+    ("status.submitted", "Credentials submitted."),
+    ("status.league_started", "League client started."),
+    ("status.league_not_started", "League client did not start in time."),
+    ("status.signed_in", "Signed in as {riot_id}."),
+    ("status.restricted", "Account restricted: {restriction}."),
+    ("status.ready_check", "Ready check accepted."),
+    ("status.killed", "League processes killed."),
+
+    ("notify.match_found", "Match found"),
+    ("notify.match_found_body", "Rusty League accepted the ready check for you."),
+    ("notify.decay", "Ranked decay"),
+
+    ("undo.undo", "↶ Undo"),
+    ("undo.riot_id", "Updated the Riot ID of {name}"),
+    ("undo.organized", "Organized {name}"),
+    ("undo.edited", "Edited {name}"),
+    ("undo.added", "Added {name}"),
+    ("undo.ungrouped", "Removed {accounts} from their group"),
+    ("undo.grouped", "Moved {accounts} to {group}"),
+    ("undo.deleted", "Deleted {name}"),
+    ("undo.moved", "Moved {name}"),
+    ("undo.restored", "Restored {name}"),
+
+    ("count.accounts.one", "1 account"),
+    ("count.accounts.few", "{count} accounts"),
+    ("count.accounts.many", "{count} accounts"),
+
+    ("organize.title", "Organize {name}"),
+    ("organize.tooltip", "Group, labels, notes and color"),
+
+    ("field.group", "Group:"),
+    ("field.group_hint", "e.g. Smurfs"),
+    ("field.labels", "Labels:"),
+    ("field.labels_hint", "Comma-separated"),
+    ("field.color", "Color:"),
+    ("field.notes", "Notes:"),
+
+    ("riot_id.title", "Riot ID changed"),
+    ("riot_id.reports", "The League client reports a new Riot ID for {username}:"),
+    ("riot_id.update", "Update"),
+    ("riot_id.keep", "Keep"),
+
+    ("champ_select.title", "Champion Select Assistant"),
+    ("champ_select.tooltip", "Champion select assistant"),
+    ("champ_select.enabled", "Enabled"),
+    ("champ_select.lock_in", "Lock in automatically"),
+    ("champ_select.lock_in_hint", "When off, champions are only hovered and you lock in yourself."),
+    ("champ_select.picks", "Picks"),
+    ("champ_select.bans", "Bans"),
+    ("champ_select.hint", "Comma-separated champion names in priority order. The first available one is used; role lists fall back to \"Any role\"."),
+    ("champ_select.hovering", "Hovering {champion}."),
+    ("champ_select.hovering_ban", "Hovering ban {champion}."),
+    ("champ_select.locked", "Locked in {champion}."),
+    ("champ_select.banned", "Banned {champion}."),
+
+    ("role.any", "Any role"),
+    ("role.top", "Top"),
+    ("role.jungle", "Jungle"),
+    ("role.middle", "Mid"),
+    ("role.bottom", "Bot"),
+    ("role.utility", "Support"),
+
+    ("presets.title", "Rune & Spell Presets"),
+    ("presets.tooltip", "Rune and summoner spell presets"),
+    ("presets.subtitle", "Applied when you lock in the champion, on every saved account."),
+    ("presets.empty", "No presets yet."),
+    ("presets.edit", "Edit preset"),
+    ("presets.new", "New preset"),
+    ("presets.champion", "Champion:"),
+    ("presets.name", "Name:"),
+    ("presets.name_hint", "Same as champion"),
+    ("presets.role", "Role:"),
+    ("presets.spells", "Spells:"),
+    ("presets.runes", "Runes:"),
+    ("presets.rune_count", "{count} runes"),
+    ("presets.not_set", "Not set"),
+    ("presets.import", "Import from client"),
+    ("presets.import_hint", "Copies the current rune page (and spells, in champion select)"),
+    ("presets.save", "Save preset"),
+    ("presets.clear", "Clear"),
+    ("presets.summary_runes", "runes"),
+    ("presets.summary_empty", "empty"),
+    ("presets.applied", "Applied preset \"{name}\" for {champion}."),
+    ("presets.failed", "Preset \"{name}\" failed: {error}"),
+
+    ("spell.flash", "Flash"),
+    ("spell.ignite", "Ignite"),
+    ("spell.teleport", "Teleport"),
+    ("spell.smite", "Smite"),
+    ("spell.heal", "Heal"),
+    ("spell.exhaust", "Exhaust"),
+    ("spell.barrier", "Barrier"),
+    ("spell.ghost", "Ghost"),
+    ("spell.cleanse", "Cleanse"),
+    ("spell.clarity", "Clarity"),
+    ("spell.mark", "Mark"),
+
+    ("trash.button", "♻ Trash ({count})"),
+    ("trash.retention", "Deleted accounts are kept for {days} days."),
+    ("trash.empty", "The trash is empty."),
+    ("trash.days_left", "{days} days left"),
+    ("trash.restore", "Restore"),
+    ("trash.delete_forever", "Delete forever"),
+
+    ("manager.title", "Account Manager"),
+    ("manager.tooltip", "Manage accounts"),
+    ("manager.subtitle", "Tick accounts for bulk actions, drag ⠿ to change the order, ✏ to edit."),
+    ("manager.add", "➕ Add account"),
+    ("manager.open", "👥 Add one in the account manager"),
+    ("manager.empty", "No saved accounts yet."),
+    ("manager.selected", "{count} selected"),
+    ("manager.group_hint", "Group"),
+    ("manager.move_to_group", "Move to group"),
+    ("manager.move_to_group_hint", "Leave the group empty to ungroup them"),
+    ("manager.delete", "🗑 Delete"),
+    ("manager.confirm_title", "Confirmation"),
+    ("manager.confirm_delete", "Are you sure you want to delete the selected accounts ({count})?"),
+
+    ("column.riot_id", "Riot ID"),
+    ("column.region", "Region"),
+    ("column.username", "Username"),
+    ("column.group", "Group"),
+    ("column.last_used", "Last used"),
+    ("column.rank", "Rank"),
+
+    ("form.new", "New account"),
+    ("form.edit", "Edit account"),
+    ("form.username", "Username:"),
+    ("form.password", "Password:"),
+    ("form.show_password", "Show/Hide password"),
+    ("form.in_game_name", "In-game name:"),
+    ("form.tag_hint", "Leave this area empty if u have standard tag based on region."),
+    ("form.region", "Region:"),
+    ("form.language", "Language:"),
+    ("form.dont_change", "Don't change"),
+    ("form.ready_check", "Ready check:"),
+    ("form.auto_accept", "Auto-accept for this account"),
+
+    ("details.title", "Account Details"),
+    ("details.tooltip", "Account details"),
+    ("details.subtitle", "Captured on every login. Click a column to sort, an account to select it."),
+    ("details.account", "Account"),
+    ("details.level", "Level"),
+    ("details.blue_essence", "BE"),
+    ("details.orange_essence", "OE"),
+    ("details.riot_points", "RP"),
+    ("details.champions", "Champions"),
+    ("details.honor", "Honor"),
+    ("details.loot", "Loot"),
+    ("details.keys", "Keys"),
+    ("details.logins", "Logins"),
+    ("details.last_used", "Last used"),
+    ("details.updated", "Updated"),
+
+    ("history.title", "Match History"),
+    ("history.tooltip", "Match history"),
+    ("history.champion_hint", "Champion"),
+    ("history.all_queues", "All queues"),
+    ("history.empty", "No games stored yet. They are collected after each login and game."),
+    ("history.summary", "{games} games · {win_rate}% win rate · {kda} KDA"),
+    ("history.date", "Date"),
+    ("history.champion", "Champion"),
+    ("history.queue", "Queue"),
+    ("history.result", "Result"),
+    ("history.kda", "K / D / A"),
+    ("history.duration", "Duration"),
+    ("history.victory", "Victory"),
+    ("history.defeat", "Defeat"),
+
+    ("queue.normal_draft", "Normal Draft"),
+    ("queue.ranked_solo", "Ranked Solo/Duo"),
+    ("queue.normal_blind", "Normal Blind"),
+    ("queue.ranked_flex", "Ranked Flex"),
+    ("queue.aram", "ARAM"),
+    ("queue.quickplay", "Quickplay"),
+    ("queue.clash", "Clash"),
+    ("queue.coop", "Co-op vs AI"),
+    ("queue.arurf", "ARURF"),
+    ("queue.arena", "Arena"),
+    ("queue.urf", "URF"),
+    ("queue.custom", "Custom"),
+    ("queue.other", "Queue {id}"),
+
+    ("settings.title", "Rusty Settings"),
+    ("settings.app_language", "App language"),
+    ("settings.theme", "Theme"),
+    ("settings.accent", "Accent"),
+    ("settings.ui_scale", "UI scale"),
+    ("settings.ui_scale_auto", "Match monitor"),
+    ("settings.riot_path", "Riot Client Services (EXE)"),
+    ("settings.executables", "Executables"),
+    ("settings.detect", "Detect Riot Client installations"),
+    ("settings.none_detected", "No Riot Client installation detected. Browse for RiotClientServices.exe manually."),
+    ("settings.detected", "Detected installations:"),
+    ("settings.detecting", "Searching for Riot Client installations..."),
+    ("settings.minimalist", "Minimalist Mode"),
+    ("settings.autostart", "Start with Windows"),
+    ("settings.restore_locale", "Restore client language after login"),
+    ("settings.auto_launch", "Launch League after sign-in"),
+    ("settings.recent_first", "Recently used accounts first"),
+    ("settings.accept_delay", "Auto-accept delay"),
+    ("settings.decay_notifications", "Decay notifications"),
+    ("settings.login_timing", "Login timing"),
+    ("settings.timing_learned", "Learned: login screen usually appears after {seconds} s ({launches} launches)."),
+    ("settings.timing_unlearned", "Nothing learned yet, using Normal until the first launch."),
+    ("settings.timing_fixed", "Waits up to {seconds} s for the login screen, {millis} ms between keystrokes."),
+    ("settings.account_order", "Account order"),
+    ("settings.confirm", "Confirm Settings"),
+    ("settings.search", "Search settings..."),
+    ("settings.reset", "Reset to default"),
+    ("settings.section.general", "General"),
+    ("settings.section.appearance", "Appearance"),
+    ("settings.section.riot_client", "Riot Client"),
+    ("settings.section.accounts", "Accounts"),
+    ("settings.section.notifications", "Notifications"),
+    ("settings.app_language_desc", "Language of the Rusty League interface."),
+    ("settings.autostart_desc", "Open Rusty League when you sign in to Windows."),
+    ("settings.minimalist_desc", "Only show the account picker and the Login and Kill buttons."),
+    ("settings.theme_desc", "Light or dark colors, or whatever Windows uses."),
+    ("settings.accent_desc", "Color of selections, links and switches."),
+    ("settings.ui_scale_desc", "Size of text and controls. Matching the monitor uses the Windows display scale."),
+    ("settings.riot_path_desc", "The Riot Client executable used to sign in."),
+    ("settings.login_timing_desc", "How long to wait for the login screen and between keystrokes."),
+    ("settings.restore_locale_desc", "Put back the Riot Client language once an account with its own language has signed in."),
+    ("settings.auto_launch_desc", "Press Play on the League of Legends page after signing in."),
+    ("settings.accept_delay_desc", "Wait this long before accepting a found match for accounts with auto-accept."),
+    ("settings.recent_first_desc", "List recently used accounts at the top of the picker."),
+    ("settings.account_order_desc", "How accounts are ordered in the picker."),
+    ("settings.record_every_game", "Record every game"),
+    ("settings.record_every_game_desc", "Keep watching the League client to save each finished game, not only the games found at sign-in."),
+    ("settings.decay_notifications_desc", "Notify when a ranked account is close to losing LP to inactivity."),
+    ("settings.decay_warning_days", "Decay warning"),
+    ("settings.decay_warning_days_desc", "How many days before decay the notification is shown."),
+    ("unit.seconds", "s"),
+    ("unit.days", "days"),
+
+    ("login.title", "Rusty League Login"),
+    ("login.choose_account", "Choose account:"),
+    ("login.copy_name", "Copy Riot ID"),
+    ("login.login", "Login To League"),
+    ("login.kill", "Kill League Process"),
+
+    ("timing.fast", "Fast"),
+    ("timing.normal", "Normal"),
+    ("timing.slow", "Slow"),
+    ("timing.adaptive", "Adaptive"),
+
+    ("sort.manual", "Manual"),
+    ("sort.name", "Name"),
+    ("sort.region", "Region"),
+    ("sort.last_used", "Last used"),
+    ("sort.most_used", "Most used"),
+    ("sort.rank", "Rank"),
+    ("theme.system", "Follow system"),
+    ("theme.light", "Light"),
+    ("theme.dark", "Dark"),
+
+    ("outcome.signed_in", "Signed in"),
+    ("outcome.league_not_started", "League did not start"),
+    ("outcome.restricted", "Account restricted"),
+    ("outcome.failed", "Launch failed"),
+
+    ("age.just_now", "just now"),
+    ("age.minutes", "{count} min ago"),
+    ("age.hours", "{count} h ago"),
+    ("age.yesterday", "yesterday"),
+    ("age.days", "{count} days ago"),
+];
+
+const POLISH: &[(&str, &str)] = &[
+    ("common.ok", "OK"),
+    ("common.save", "Zapisz"),
+    ("common.cancel", "Anuluj"),
+    ("common.edit", "Edytuj"),
+    ("common.delete", "Usuń"),
+    ("common.yes", "Tak"),
+    ("common.no", "Nie"),
+    ("common.back_without_saving", "Wróć bez zapisywania"),
+    ("common.unranked", "Bez rangi"),
+    ("common.none", "Brak"),
+
+    ("alert.title", "Informacja"),
+
+    ("error.generic", "Błąd: {error}"),
+    ("error.save_accounts", "Błąd zapisu kont: {error}"),
+    ("error.save_settings", "Błąd zapisu ustawień: {error}"),
+    ("error.save_trash", "Błąd zapisu kosza: {error}"),
+    ("error.move_history", "Nie udało się przenieść historii meczów konta {name}: {error}"),
+    ("error.save_history", "Błąd zapisu historii meczów: {error}"),
+    ("error.notification", "Nie udało się wyświetlić powiadomienia: {error}"),
+    ("error.client_settings_apply", "Nie udało się ustawić regionu i języka klienta w {error}"),
+    ("error.client_settings_restore", "Nie udało się przywrócić ustawień klienta w {error}"),
+    ("error.autostart", "Błąd autostartu: {error}"),
+    ("error.choose_account", "Wybierz konto!"),
+    ("error.account_missing", "Konto już nie istnieje."),
+    ("error.username_empty", "Nazwa użytkownika nie może być pusta!"),
+    ("error.username_taken", "Konto o nazwie użytkownika {username} już istnieje!"),
+    ("error.save_account_first", "Najpierw zapisz konto!"),
+    ("error.champion_required", "Wpisz nazwę bohatera!"),
+    ("error.preset_empty", "Zaimportuj stronę run lub wybierz dwa czary przywoływacza!"),
+
+    ("install.path_empty", "Ścieżka nie może być pusta!"),
+    ("install.missing", "{path} nie istnieje. Klient Riot mógł zostać przeniesiony lub odinstalowany."),
+    ("install.folder", "{path} to folder, a nie {exe}."),
+    ("install.wrong_file", "Wybrany plik to {file}, oczekiwano {exe}."),
+    ("install.unreadable", "Nie można odczytać {path}: {error}"),
+    ("install.not_executable", "{path} nie jest prawidłowym plikiem wykonywalnym Windows."),
+    ("install.wrong_publisher", "Wydawcą {path} jest \"{company}\", a nie Riot Games."),
+    ("install.incomplete", "Znaleziono {exe}, ale brakuje obok niego reszty klienta Riot. Zainstaluj klienta Riot ponownie lub wybierz inną instalację."),
+    ("install.not_found", "Nie znaleziono instalacji klienta Riot. Ustaw ścieżkę do RiotClientServices.exe w Ustawieniach."),
+    ("install.unavailable", "Klient Riot jest niedostępny: {error} Sprawdź ścieżkę w Ustawieniach."),
+    ("install.check_failed", "Nie udało się sprawdzić instalacji klienta Riot."),
+    ("install.checking", "Sprawdzanie klienta Riot..."),
+
+    ("card.solo", "Solo {rank}"),
+    ("card.flex", "Flex {rank}"),
+    ("card.level", "Poz. {level}"),
+
+    ("restriction.banned", "Zbanowane"),
+    ("restriction.suspended", "Zawieszone"),
+    ("restriction.leaver_buster", "Leaver Buster"),
+    ("restriction.ranked", "Blokada rankingowych"),
+    ("restriction.chat", "Blokada czatu"),
+    ("restriction.until", "{name} do {date}"),
+    ("restriction.games.one", "{name} (1 gra)"),
+    ("restriction.games.few", "{name} ({games} gry)"),
+    ("restriction.games.many", "{name} ({games} gier)"),
+
+    ("decay.decaying", "decay trwa"),
+    ("decay.in", "decay za {days} d. ({banked} w banku)"),
+
+    ("lcu.not_running", "Klient League nie jest uruchomiony (nie znaleziono pliku lockfile)."),
+    ("lcu.request_failed", "{method} {endpoint} nie powiodło się: {error}"),
+
+    ("picker.placeholder", "Wybierz konto..."),
+    ("picker.search_hint", "🔍 Szukaj kont..."),
+    ("picker.change_order", "Zmień kolejność"),
+    ("picker.group", "▤ Grupuj"),
+    ("picker.all", "Wszystkie"),
+    ("picker.recently_used", "Ostatnio używane"),
+    ("picker.all_accounts", "Wszystkie konta"),
+    ("picker.no_matches", "Brak pasujących kont."),
+    ("picker.ungrouped", "Bez grupy"),
+    ("picker.last_used", "{outcome}użyte {age}"),
+
+    ("status.starting", "Uruchamianie Riot Client..."),
+    ("status.login_screen", "Znaleziono ekran logowania, wpisywanie danych..."),
+    ("status.login_screen_timeout", "Nie znaleziono ekranu logowania na czas, mimo to wpisywanie danych..."),
+    ("status.submitted_launching", "Dane wysłane, uruchamianie League..."),
+    ("status.submitted", "Dane wysłane."),
+    ("status.league_started", "Klient League uruchomiony."),
+    ("status.league_not_started", "Klient League nie uruchomił się na czas."),
+    ("status.signed_in", "Zalogowano jako {riot_id}."),
+    ("status.restricted", "Konto ograniczone: {restriction}."),
+    ("status.ready_check", "Gotowość zaakceptowana."),
+    ("status.killed", "Procesy League zostały zamknięte."),
+
+    ("notify.match_found", "Znaleziono mecz"),
+    ("notify.match_found_body", "Rusty League zaakceptował gotowość za Ciebie."),
+    ("notify.decay", "Decay rankingowy"),
+
+    ("undo.undo", "↶ Cofnij"),
+    ("undo.riot_id", "Zaktualizowano Riot ID konta {name}"),
+    ("undo.organized", "Uporządkowano {name}"),
+    ("undo.edited", "Edytowano {name}"),
+    ("undo.added", "Dodano {name}"),
+    ("undo.ungrouped", "Usunięto z grupy: {accounts}"),
+    ("undo.grouped", "Przeniesiono do grupy {group}: {accounts}"),
+    ("undo.deleted", "Usunięto {name}"),
+    ("undo.moved", "Przeniesiono {name}"),
+    ("undo.restored", "Przywrócono {name}"),
+
+    ("count.accounts.one", "1 konto"),
+    ("count.accounts.few", "{count} konta"),
+    ("count.accounts.many", "{count} kont"),
+
+    ("organize.title", "Uporządkuj {name}"),
+    ("organize.tooltip", "Grupa, etykiety, notatki i kolor"),
+
+    ("field.group", "Grupa:"),
+    ("field.group_hint", "np. Smurfy"),
+    ("field.labels", "Etykiety:"),
+    ("field.labels_hint", "Oddzielone przecinkami"),
+    ("field.color", "Kolor:"),
+    ("field.notes", "Notatki:"),
+
+    ("riot_id.title", "Zmiana Riot ID"),
+    ("riot_id.reports", "Klient League zgłasza nowe Riot ID dla {username}:"),
+    ("riot_id.update", "Zaktualizuj"),
+    ("riot_id.keep", "Zachowaj"),
+
+    ("champ_select.title", "Asystent wyboru bohatera"),
+    ("champ_select.tooltip", "Asystent wyboru bohatera"),
+    ("champ_select.enabled", "Włączony"),
+    ("champ_select.lock_in", "Zatwierdzaj automatycznie"),
+    ("champ_select.lock_in_hint", "Gdy wyłączone, bohaterowie są tylko podświetlani, a zatwierdzasz samodzielnie."),
+    ("champ_select.picks", "Wybory"),
+    ("champ_select.bans", "Bany"),
+    ("champ_select.hint", "Nazwy bohaterów oddzielone przecinkami, w kolejności priorytetu. Używany jest pierwszy dostępny; gdy lista roli się wyczerpie, używana jest lista \"Dowolna rola\"."),
+    ("champ_select.hovering", "Podświetlono {champion}."),
+    ("champ_select.hovering_ban", "Podświetlono bana: {champion}."),
+    ("champ_select.locked", "Zatwierdzono {champion}."),
+    ("champ_select.banned", "Zbanowano {champion}."),
+
+    ("role.any", "Dowolna rola"),
+    ("role.top", "Góra"),
+    ("role.jungle", "Dżungla"),
+    ("role.middle", "Środek"),
+    ("role.bottom", "Dół"),
+    ("role.utility", "Wsparcie"),
+
+    ("presets.title", "Zestawy run i czarów"),
+    ("presets.tooltip", "Zestawy run i czarów przywoływacza"),
+    ("presets.subtitle", "Stosowane po zatwierdzeniu bohatera, na każdym zapisanym koncie."),
+    ("presets.empty", "Brak zestawów."),
+    ("presets.edit", "Edytuj zestaw"),
+    ("presets.new", "Nowy zestaw"),
+    ("presets.champion", "Bohater:"),
+    ("presets.name", "Nazwa:"),
+    ("presets.name_hint", "Jak bohater"),
+    ("presets.role", "Rola:"),
+    ("presets.spells", "Czary:"),
+    ("presets.runes", "Runy:"),
+    ("presets.rune_count", "Runy: {count}"),
+    ("presets.not_set", "Nie ustawiono"),
+    ("presets.import", "Importuj z klienta"),
+    ("presets.import_hint", "Kopiuje bieżącą stronę run (i czary, w trakcie wyboru bohatera)"),
+    ("presets.save", "Zapisz zestaw"),
+    ("presets.clear", "Wyczyść"),
+    ("presets.summary_runes", "runy"),
+    ("presets.summary_empty", "pusty"),
+    ("presets.applied", "Zastosowano zestaw \"{name}\" dla {champion}."),
+    ("presets.failed", "Zestaw \"{name}\" nie zadziałał: {error}"),
+
+    ("spell.flash", "Błysk"),
+    ("spell.ignite", "Podpalenie"),
+    ("spell.teleport", "Teleportacja"),
+    ("spell.smite", "Porażenie"),
+    ("spell.heal", "Uzdrowienie"),
+    ("spell.exhaust", "Wyczerpanie"),
+    ("spell.barrier", "Bariera"),
+    ("spell.ghost", "Duch"),
+    ("spell.cleanse", "Oczyszczenie"),
+    ("spell.clarity", "Jasność umysłu"),
+    ("spell.mark", "Śnieżka"),
+
+    ("trash.button", "♻ Kosz ({count})"),
+    ("trash.retention", "Usunięte konta są przechowywane przez {days} dni."),
+    ("trash.empty", "Kosz jest pusty."),
+    ("trash.days_left", "Pozostało dni: {days}"),
+    ("trash.restore", "Przywróć"),
+    ("trash.delete_forever", "Usuń na zawsze"),
+
+    ("manager.title", "Menedżer kont"),
+    ("manager.tooltip", "Zarządzaj kontami"),
+    ("manager.subtitle", "Zaznacz konta, aby wykonać akcje zbiorcze, przeciągnij ⠿, aby zmienić kolejność, kliknij ✏, aby edytować."),
+    ("manager.add", "➕ Dodaj konto"),
+    ("manager.open", "👥 Dodaj je w menedżerze kont"),
+    ("manager.empty", "Brak zapisanych kont."),
+    ("manager.selected", "Zaznaczone: {count}"),
+    ("manager.group_hint", "Grupa"),
+    ("manager.move_to_group", "Przenieś do grupy"),
+    ("manager.move_to_group_hint", "Zostaw pustą grupę, aby usunąć je z grup"),
+    ("manager.delete", "🗑 Usuń"),
+    ("manager.confirm_title", "Potwierdzenie"),
+    ("manager.confirm_delete", "Czy na pewno chcesz usunąć zaznaczone konta ({count})?"),
+
+    ("column.riot_id", "Riot ID"),
+    ("column.region", "Region"),
+    ("column.username", "Nazwa użytkownika"),
+    ("column.group", "Grupa"),
+    ("column.last_used", "Ostatnio użyte"),
+    ("column.rank", "Ranga"),
+
+    ("form.new", "Nowe konto"),
+    ("form.edit", "Edytuj konto"),
+    ("form.username", "Nazwa użytkownika:"),
+    ("form.password", "Hasło:"),
+    ("form.show_password", "Pokaż/ukryj hasło"),
+    ("form.in_game_name", "Nazwa w grze:"),
+    ("form.tag_hint", "Zostaw to pole puste, jeśli masz standardowy tag regionu."),
+    ("form.region", "Region:"),
+    ("form.language", "Język klienta:"),
+    ("form.dont_change", "Nie zmieniaj"),
+    ("form.ready_check", "Gotowość:"),
+    ("form.auto_accept", "Automatycznie akceptuj na tym koncie"),
+
+    ("details.title", "Szczegóły kont"),
+    ("details.tooltip", "Szczegóły kont"),
+    ("details.subtitle", "Zbierane przy każdym logowaniu. Kliknij kolumnę, aby sortować, a konto, aby je wybrać."),
+    ("details.account", "Konto"),
+    ("details.level", "Poziom"),
+    ("details.blue_essence", "BE"),
+    ("details.orange_essence", "OE"),
+    ("details.riot_points", "RP"),
+    ("details.champions", "Bohaterowie"),
+    ("details.honor", "Honor"),
+    ("details.loot", "Łupy"),
+    ("details.keys", "Klucze"),
+    ("details.logins", "Logowania"),
+    ("details.last_used", "Ostatnio użyte"),
+    ("details.updated", "Aktualizacja"),
+
+    ("history.title", "Historia meczów"),
+    ("history.tooltip", "Historia meczów"),
+    ("history.champion_hint", "Bohater"),
+    ("history.all_queues", "Wszystkie kolejki"),
+    ("history.empty", "Brak zapisanych gier. Są zbierane po każdym logowaniu i grze."),
+    ("history.summary", "Gry: {games} · {win_rate}% wygranych · KDA {kda}"),
+    ("history.date", "Data"),
+    ("history.champion", "Bohater"),
+    ("history.queue", "Kolejka"),
+    ("history.result", "Wynik"),
+    ("history.kda", "K / D / A"),
+    ("history.duration", "Czas"),
+    ("history.victory", "Zwycięstwo"),
+    ("history.defeat", "Porażka"),
+
+    ("queue.normal_draft", "Normalna (wybór)"),
+    ("queue.ranked_solo", "Rankingowa solo/duet"),
+    ("queue.normal_blind", "Normalna (w ciemno)"),
+    ("queue.ranked_flex", "Rankingowa elastyczna"),
+    ("queue.aram", "ARAM"),
+    ("queue.quickplay", "Szybka gra"),
+    ("queue.clash", "Clash"),
+    ("queue.coop", "Kooperacja przeciwko SI"),
+    ("queue.arurf", "ARURF"),
+    ("queue.arena", "Arena"),
+    ("queue.urf", "URF"),
+    ("queue.custom", "Niestandardowa"),
+    ("queue.other", "Kolejka {id}"),
+
+    ("settings.title", "Ustawienia Rusty"),
+    ("settings.app_language", "Język aplikacji"),
+    ("settings.theme", "Motyw"),
+    ("settings.accent", "Akcent"),
+    ("settings.ui_scale", "Skala interfejsu"),
+    ("settings.ui_scale_auto", "Jak monitor"),
+    ("settings.riot_path", "Riot Client Services (EXE)"),
+    ("settings.executables", "Pliki wykonywalne"),
+    ("settings.detect", "Wykryj instalacje Riot Client"),
+    ("settings.none_detected", "Nie wykryto instalacji Riot Client. Wskaż plik RiotClientServices.exe ręcznie."),
+    ("settings.detected", "Wykryte instalacje:"),
+    ("settings.detecting", "Szukanie instalacji Riot Client..."),
+    ("settings.minimalist", "Tryb minimalistyczny"),
+    ("settings.autostart", "Uruchamiaj z systemem Windows"),
+    ("settings.restore_locale", "Przywracaj język klienta po zalogowaniu"),
+    ("settings.auto_launch", "Uruchamiaj League po zalogowaniu"),
+    ("settings.recent_first", "Ostatnio używane konta na górze"),
+    ("settings.accept_delay", "Opóźnienie akceptacji"),
+    ("settings.decay_notifications", "Powiadomienia o decayu"),
+    ("settings.login_timing", "Czas logowania"),
+    ("settings.timing_learned", "Nauczone: ekran logowania zwykle pojawia się po {seconds} s (uruchomienia: {launches})."),
+    ("settings.timing_unlearned", "Nic jeszcze nie nauczono, do pierwszego uruchomienia używany jest profil Normalny."),
+    ("settings.timing_fixed", "Czeka do {seconds} s na ekran logowania, {millis} ms między naciśnięciami klawiszy."),
+    ("settings.account_order", "Kolejność kont"),
+    ("settings.confirm", "Zatwierdź ustawienia"),
+    ("settings.search", "Szukaj ustawień..."),
+    ("settings.reset", "Przywróć domyślne"),
+    ("settings.section.general", "Ogólne"),
+    ("settings.section.appearance", "Wygląd"),
+    ("settings.section.riot_client", "Riot Client"),
+    ("settings.section.accounts", "Konta"),
+    ("settings.section.notifications", "Powiadomienia"),
+    ("settings.app_language_desc", "Język interfejsu Rusty League."),
+    ("settings.autostart_desc", "Otwieraj Rusty League po zalogowaniu do systemu Windows."),
+    ("settings.minimalist_desc", "Pokazuj tylko wybór konta oraz przyciski logowania i zamykania."),
+    ("settings.theme_desc", "Jasne lub ciemne kolory albo takie, jak w systemie Windows."),
+    ("settings.accent_desc", "Kolor zaznaczeń, linków i przełączników."),
+    ("settings.ui_scale_desc", "Rozmiar tekstu i kontrolek. Ustawienie jak monitor używa skali ekranu Windows."),
+    ("settings.riot_path_desc", "Plik Riot Client używany do logowania."),
+    ("settings.login_timing_desc", "Jak długo czekać na ekran logowania i między naciśnięciami klawiszy."),
+    ("settings.restore_locale_desc", "Przywracaj język Riot Client po zalogowaniu konta z własnym językiem."),
+    ("settings.auto_launch_desc", "Naciskaj Graj na stronie League of Legends po zalogowaniu."),
+    ("settings.accept_delay_desc", "Czekaj tyle przed akceptacją znalezionego meczu na kontach z automatyczną akceptacją."),
+    ("settings.recent_first_desc", "Pokazuj ostatnio używane konta na górze listy."),
+    ("settings.account_order_desc", "Kolejność kont na liście wyboru."),
+    ("settings.record_every_game", "Zapisuj każdą grę"),
+    ("settings.record_every_game_desc", "Obserwuj klienta League, aby zapisywać każdą zakończoną grę, a nie tylko gry znalezione przy logowaniu."),
+    ("settings.decay_notifications_desc", "Powiadamiaj, gdy konto rankingowe zbliża się do utraty LP za nieaktywność."),
+    ("settings.decay_warning_days", "Ostrzeżenie o decayu"),
+    ("settings.decay_warning_days_desc", "Ile dni przed decayem pokazywać powiadomienie."),
+    ("unit.seconds", "s"),
+    ("unit.days", "dni"),
+
+    ("login.title", "Logowanie Rusty League"),
+    ("login.choose_account", "Wybierz konto:"),
+    ("login.copy_name", "Skopiuj nick"),
+    ("login.login", "Zaloguj do League"),
+    ("login.kill", "Zamknij procesy League"),
+
+    ("timing.fast", "Szybki"),
+    ("timing.normal", "Normalny"),
+    ("timing.slow", "Wolny"),
+    ("timing.adaptive", "Adaptacyjny"),
+
+    ("sort.manual", "Ręczna"),
+    ("sort.name", "Nazwa"),
+    ("sort.region", "Region"),
+    ("sort.last_used", "Ostatnio użyte"),
+    ("sort.most_used", "Najczęściej używane"),
+    ("sort.rank", "Ranga"),
+    ("theme.system", "Jak w systemie"),
+    ("theme.light", "Jasny"),
+    ("theme.dark", "Ciemny"),
+
+    ("outcome.signed_in", "Zalogowano"),
+    ("outcome.league_not_started", "League się nie uruchomiło"),
+    ("outcome.restricted", "Konto ograniczone"),
+    ("outcome.failed", "Uruchomienie nie powiodło się"),
+
+    ("age.just_now", "przed chwilą"),
+    ("age.minutes", "{count} min temu"),
+    ("age.hours", "{count} godz. temu"),
+    ("age.yesterday", "wczoraj"),
+    ("age.days", "{count} dni temu"),
+];
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn every_language_has_every_key() {
+        let english = catalog(Language::English);
+        for language in Language::ALL {
+            let translated = catalog(language);
+            for key in english.keys() {
+                assert!(translated.contains_key(key), "{:?} is missing \"{}\"", language, key);
+            }
+            for key in translated.keys() {
+                assert!(english.contains_key(key), "{:?} has \"{}\", which English does not", language, key);
+            }
+        }
+    }
+
+    #[test]
+    fn catalogs_have_no_duplicate_keys() {
+        for language in Language::ALL {
+            assert_eq!(
+                language.entries().len(),
+                catalog(language).len(),
+                "{:?} lists a key more than once",
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn translations_keep_their_placeholders() {
+        let english = catalog(Language::English);
+        for language in Language::ALL {
+            for (key, text) in catalog(language) {
+                assert_eq!(placeholders(text), placeholders(english[key]), "{:?} \"{}\"", language, key);
+            }
+        }
+    }
+
+    #[test]
+    fn keys_used_in_the_ui_exist() {
+        let english = catalog(Language::English);
+        // Every module but this one, whose comments show the macro with a made-up key.
+        let sources = [
+            include_str!("main.rs"),
+            include_str!("app.rs"),
+            include_str!("launcher.rs"),
+            include_str!("credentials.rs"),
+            include_str!("settings.rs"),
+            include_str!("riot_client.rs"),
+            include_str!("lcu.rs"),
+            include_str!("decay.rs"),
+            include_str!("notifications.rs"),
+            include_str!("client_watcher.rs"),
+            include_str!("champ_select.rs"),
+            include_str!("presets.rs"),
+            include_str!("restrictions.rs"),
+            include_str!("wallet.rs"),
+            include_str!("match_history.rs"),
+            include_str!("trash.rs"),
+            include_str!("theme.rs"),
+            include_str!("widgets.rs"),
+            include_str!("settings_page.rs"),
+        ];
+        for source in sources {
+            for (start, pattern) in source.match_indices("t!(\"") {
+                // Skips format!(", print!(" and the like.
+                if source[..start].ends_with(|c: char| c.is_alphanumeric() || c == '_') {
+                    continue;
+                }
+                let rest = &source[start + pattern.len()..];
+                let key = rest.split('"').next().unwrap_or_default();
+                assert!(english.contains_key(key), "\"{}\" is not in the catalog", key);
+            }
+        }
+    }
+
+    #[test]
+    fn settings_page_keys_exist() {
+        use crate::settings_page::{SETTINGS, Section, Widget};
+
+        let english = catalog(Language::English);
+        let mut keys: Vec<&str> = Section::ALL.iter().map(Section::label_key).collect();
+        for def in SETTINGS {
+            keys.extend([def.label, def.description]);
+            if let Widget::Decimal { unit, .. } | Widget::Integer { unit, .. } = def.widget {
+                keys.push(unit);
+            }
+        }
+        for key in keys {
+            assert!(english.contains_key(key), "\"{}\" is not in the catalog", key);
+        }
+    }
+
+    #[test]
+    fn polish_plurals() {
+        let forms: Vec<&str> = [1, 2, 5, 12, 22, 25, 112, 0]
+            .into_iter()
+            .map(|count| plural_form(Language::Polish, count))
+            .collect();
+        assert_eq!(forms, ["one", "few", "many", "many", "few", "many", "many", "many"]);
+    }
+
+    #[test]
+    fn english_plurals() {
+        let forms: Vec<&str> = [0, 1, 2, 21].into_iter().map(|count| plural_form(Language::English, count)).collect();
+        assert_eq!(forms, ["many", "one", "many", "many"]);
+    }
+}
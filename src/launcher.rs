@@ -1,8 +1,51 @@
 use std::process::Command;
 use std::os::windows::process::CommandExt;
+use std::sync::mpsc;
 use std::{thread, time};
 use enigo::{Direction, Enigo, Key, Keyboard, Settings};
+use uiautomation::patterns::UIInvokePattern;
 use uiautomation::{UIAutomation, UIElement};
+use crate::lcu::{self, LcuClient, RankedStats, Summoner};
+use crate::decay;
+use crate::champ_select::ChampionIndex;
+use crate::match_history::{self, StoredMatch};
+use crate::credentials;
+use crate::restrictions::{self, Restriction};
+use crate::wallet::{self, WalletSnapshot};
+use crate::riot_client;
+use crate::settings::LoginTiming;
+use crate::i18n::t;
+
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+const LEAGUE_CLIENT_PROCESS: &str = "LeagueClientUx.exe";
+
+pub enum LaunchEvent {
+    LaunchFailed(String),
+    ClientSettingsNotApplied(String),
+    ClientSettingsNotRestored(String),
+    LoginScreenFound(time::Duration),
+    LoginScreenTimedOut(time::Duration),
+    CredentialsSubmitted,
+    LeagueClientStarted,
+    LeagueClientNotStarted,
+    SummonerLoaded(Summoner),
+    RankedLoaded {
+        ranked: RankedStats,
+        last_solo_game_at: Option<u64>,
+    },
+    RestrictionsLoaded(Vec<Restriction>),
+    WalletLoaded(WalletSnapshot),
+    MatchesLoaded(Vec<StoredMatch>),
+}
+
+pub struct LaunchOptions {
+    pub riot_path: String,
+    pub timing: LoginTiming,
+    pub region: String,
+    pub locale: String,
+    pub restore_client_settings: bool,
+    pub auto_launch_league: bool,
+}
 
 pub fn kill_league_processes() {
     let processes = [
@@ -14,8 +57,6 @@ pub fn kill_league_processes() {
         "League of Legends.exe",
     ];
 
-    const CREATE_NO_WINDOW: u32 = 0x08000000;
-
     for process in processes {
         let _ = Command::new("taskkill")
             .args(["/F", "/IM", process])
@@ -24,52 +65,266 @@ pub fn kill_league_processes() {
     }
 }
 
-pub fn launch_and_login(username: String, password: String, riot_path: String) -> std::io::Result<()> {
-    let mut path = riot_path;
-    
-    if path.is_empty() {
-        path = r"E:\Riot Games\Riot Client\RiotClientServices.exe".to_string();
-    }
-    
-    let child = Command::new(path)
-        .arg("--launch-product=league_of_legends")
-        .arg("--launch-patchline=live")
-        .spawn()?;
+pub fn launch_and_login(username: String, password: String, options: LaunchOptions) -> mpsc::Receiver<LaunchEvent> {
+    let LaunchOptions { riot_path, timing, region, locale, restore_client_settings, auto_launch_league } = options;
 
-    let pid = child.id();
+    let (tx, rx) = mpsc::channel();
 
     thread::spawn(move || {
-        let found_element = wait_for_login_screen(pid); 
+        // Detecting the install and checking its publisher both ask PowerShell, so they
+        // happen here and not in a frame.
+        let path = if riot_path.is_empty() {
+            match riot_client::detect_install_paths().into_iter().next() {
+                Some(path) => path,
+                None => {
+                    let _ = tx.send(LaunchEvent::LaunchFailed(t!("install.not_found").to_owned()));
+                    return;
+                }
+            }
+        } else {
+            riot_path
+        };
+        if let Err(e) = riot_client::validate_install(&path) {
+            let _ = tx.send(LaunchEvent::LaunchFailed(t!("install.unavailable", error = e)));
+            return;
+        }
+
+        let (client_settings_backup, failed) = riot_client::apply_region_and_locale(&region, &locale);
+        for (file, e) in failed {
+            let _ = tx.send(LaunchEvent::ClientSettingsNotApplied(format!("{}: {}", file.display(), e)));
+        }
+
+        let child = Command::new(path)
+            .arg("--launch-product=league_of_legends")
+            .arg("--launch-patchline=live")
+            .spawn();
+        let child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                let _ = tx.send(LaunchEvent::LaunchFailed(e.to_string()));
+                send_restore_failures(&tx, client_settings_backup.restore());
+                return;
+            }
+        };
+
+        let pid = child.id();
+        let started = time::Instant::now();
+        let found_element = wait_for_login_screen(pid, &timing); 
 
         if let Some(target_element) = found_element {
+            let _ = tx.send(LaunchEvent::LoginScreenFound(started.elapsed()));
+
             if let Err(_e) = target_element.set_focus() {
             }
             
-            thread::sleep(time::Duration::from_millis(500));
+            thread::sleep(timing.focus_delay);
         } else {
-            thread::sleep(time::Duration::from_secs(5));
+            let _ = tx.send(LaunchEvent::LoginScreenTimedOut(started.elapsed()));
+            thread::sleep(timing.fallback_delay);
         }
 
         if let Ok(mut enigo) = Enigo::new(&Settings::default()) {
             let _ = enigo.text(&username);
-            thread::sleep(time::Duration::from_millis(100));
+            thread::sleep(timing.keystroke_delay);
 
             let _ = enigo.key(Key::Tab, Direction::Click);
-            thread::sleep(time::Duration::from_millis(100));
+            thread::sleep(timing.keystroke_delay);
 
             let _ = enigo.text(&password);
-            thread::sleep(time::Duration::from_millis(100));
+            thread::sleep(timing.keystroke_delay);
 
             let _ = enigo.key(Key::Return, Direction::Click);
+            let _ = tx.send(LaunchEvent::CredentialsSubmitted);
         } else {
              eprintln!("Failed to initialize enigo");
         }
+
+        let league_started = if auto_launch_league {
+            launch_league_from_product_page(&timing)
+        } else {
+            wait_for_process(LEAGUE_CLIENT_PROCESS, timing.league_client_timeout)
+        };
+
+        let _ = tx.send(if league_started {
+            LaunchEvent::LeagueClientStarted
+        } else {
+            LaunchEvent::LeagueClientNotStarted
+        });
+
+        if !league_started {
+            let found = find_account_bans();
+            if !found.is_empty() {
+                let _ = tx.send(LaunchEvent::RestrictionsLoaded(found));
+            }
+        }
+
+        let session = if league_started {
+            wait_for_league_api(&timing)
+        } else {
+            None
+        };
+
+        if restore_client_settings {
+            if session.is_none() {
+                // The League client reads its settings while starting up.
+                thread::sleep(time::Duration::from_secs(10));
+            }
+            send_restore_failures(&tx, client_settings_backup.restore());
+        }
+
+        let Some((client, summoner)) = session else {
+            return;
+        };
+
+        let _ = tx.send(LaunchEvent::SummonerLoaded(summoner));
+        // A failed lookup says nothing about the account, so the stored restrictions stay.
+        if let Ok(found) = restrictions::from_client(&client) {
+            let _ = tx.send(LaunchEvent::RestrictionsLoaded(found));
+        }
+
+        let recent_games = client.recent_matches(20).unwrap_or_default();
+
+        if let Ok(ranked) = client.ranked_stats() {
+            let last_solo_game_at = recent_games
+                .iter()
+                .filter(|game| game.queue_id == decay::SOLO_DUO_QUEUE_ID)
+                .map(|game| game.game_creation / 1000)
+                .max();
+            let _ = tx.send(LaunchEvent::RankedLoaded { ranked, last_solo_game_at });
+        }
+
+        if !recent_games.is_empty() {
+            let index = client.champion_summaries().ok().map(|summaries| ChampionIndex::new(&summaries));
+            let _ = tx.send(LaunchEvent::MatchesLoaded(match_history::from_games(&recent_games, index.as_ref())));
+        }
+
+        if let Some(snapshot) = wallet::from_client(&client) {
+            let _ = tx.send(LaunchEvent::WalletLoaded(snapshot));
+        }
     });
     
-    Ok(())
+    rx
+}
+
+fn send_restore_failures(tx: &mpsc::Sender<LaunchEvent>, failed: Vec<riot_client::FileError>) {
+    for (file, e) in failed {
+        let _ = tx.send(LaunchEvent::ClientSettingsNotRestored(format!("{}: {}", file.display(), e)));
+    }
+}
+
+// The lockfile appears before the client has finished signing in, so wait until it knows the summoner.
+// League is already running here, so its install folder is looked up once and only the lockfile is polled.
+fn wait_for_league_api(timing: &LoginTiming) -> Option<(LcuClient, Summoner)> {
+    let install_dirs = riot_client::league_install_dirs();
+    let start = time::Instant::now();
+    while start.elapsed() < timing.league_client_timeout {
+        if let Some(lockfile) = lcu::find_lockfile_in(&install_dirs) {
+            let client = LcuClient::from_lockfile(&lockfile);
+            if let Ok(summoner) = client.current_summoner()
+                && !summoner.puuid.is_empty()
+            {
+                return Some((client, summoner));
+            }
+        }
+        thread::sleep(time::Duration::from_secs(2));
+    }
+    None
+}
+
+fn is_process_running(image_name: &str) -> bool {
+    let output = Command::new("tasklist")
+        .args(["/FI", &format!("IMAGENAME eq {}", image_name), "/NH"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .output();
+
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .to_ascii_lowercase()
+            .contains(&image_name.to_ascii_lowercase()),
+        Err(_) => false,
+    }
+}
+
+fn wait_for_process(image_name: &str, timeout: time::Duration) -> bool {
+    let start = time::Instant::now();
+    while start.elapsed() < timeout {
+        if is_process_running(image_name) {
+            return true;
+        }
+        thread::sleep(time::Duration::from_secs(1));
+    }
+    false
+}
+
+// Some Riot Client versions stop on the League product page after sign-in instead of
+// launching the game, so press its Play button until LeagueClientUx.exe shows up.
+fn launch_league_from_product_page(timing: &LoginTiming) -> bool {
+    let uia = UIAutomation::new().ok();
+    let start = time::Instant::now();
+    let mut play_clicked = false;
+
+    while start.elapsed() < timing.league_client_timeout {
+        if is_process_running(LEAGUE_CLIENT_PROCESS) {
+            return true;
+        }
+
+        if !play_clicked
+            && let Some(button) = uia.as_ref().and_then(find_play_button)
+        {
+            play_clicked = press_button(&button);
+        }
+
+        thread::sleep(timing.heavy_search_interval);
+    }
+
+    false
+}
+
+fn find_play_button(uia: &UIAutomation) -> Option<UIElement> {
+    let root = uia.get_root_element().ok()?;
+    let windows = uia.create_matcher().from(root).name("Riot Client").find_all().ok()?;
+
+    for window in windows {
+        for name in ["Play", "PLAY"] {
+            let button_matcher = uia.create_matcher()
+                .from(window.clone())
+                .control_type(uiautomation::types::ControlType::Button)
+                .name(name)
+                .timeout(50);
+
+            if let Ok(button) = button_matcher.find_first()
+                && button.is_enabled().unwrap_or(false)
+            {
+                return Some(button);
+            }
+        }
+    }
+
+    None
+}
+
+// A banned or suspended account never gets past the Riot Client, which shows the reason instead.
+// Asks the Riot Client's own API, since a banned account never reaches the League client.
+fn find_account_bans() -> Vec<Restriction> {
+    let Some(lockfile) = lcu::find_riot_client_lockfile() else {
+        return Vec::new();
+    };
+    LcuClient::from_lockfile(&lockfile)
+        .riot_user_info()
+        .map(|info| restrictions::from_account_bans(&info, credentials::unix_now()))
+        .unwrap_or_default()
+}
+
+fn press_button(button: &UIElement) -> bool {
+    if let Ok(invoke) = button.get_pattern::<UIInvokePattern>()
+        && invoke.invoke().is_ok()
+    {
+        return true;
+    }
+    button.click().is_ok()
 }
 
-fn wait_for_login_screen(target_pid: u32) -> Option<UIElement> {
+fn wait_for_login_screen(target_pid: u32, timing: &LoginTiming) -> Option<UIElement> {
     let uia = match UIAutomation::new() {
         Ok(u) => u,
         Err(_e) => {
@@ -78,7 +333,7 @@ fn wait_for_login_screen(target_pid: u32) -> Option<UIElement> {
     };
 
     let start = time::Instant::now();
-    let timeout = time::Duration::from_secs(60); 
+    let timeout = timing.login_screen_timeout;
     let mut last_heavy_search = time::Instant::now(); 
     
     while start.elapsed() < timeout {
@@ -134,7 +389,7 @@ fn wait_for_login_screen(target_pid: u32) -> Option<UIElement> {
             }
         }
 
-        if last_heavy_search.elapsed().as_millis() > 1000 {
+        if last_heavy_search.elapsed() > timing.heavy_search_interval {
             last_heavy_search = time::Instant::now();
             
             if let Ok(root) = uia.get_root_element() {
@@ -159,7 +414,7 @@ fn wait_for_login_screen(target_pid: u32) -> Option<UIElement> {
             }
         }
         
-        thread::sleep(time::Duration::from_millis(50));
+        thread::sleep(timing.poll_interval);
     }
 
     None
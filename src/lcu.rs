@@ -0,0 +1,711 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::sync::{Arc, OnceLock};
+use ureq::unversioned::resolver::DefaultResolver;
+use ureq::unversioned::transport::{Connector, TcpConnector};
+use crate::i18n::t;
+use crate::lcu_tls;
+use crate::riot_client;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lockfile {
+    pub process: String,
+    pub pid: u32,
+    pub port: u16,
+    pub password: String,
+    pub protocol: String,
+}
+
+impl Lockfile {
+    // Format: `LeagueClient:<pid>:<port>:<password>:<protocol>`
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut parts = content.trim().split(':');
+        let lockfile = Self {
+            process: parts.next()?.to_owned(),
+            pid: parts.next()?.parse().ok()?,
+            port: parts.next()?.parse().ok()?,
+            password: parts.next()?.to_owned(),
+            protocol: parts.next()?.to_owned(),
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(lockfile)
+    }
+
+    pub fn read(path: &Path) -> Option<Self> {
+        fs::read_to_string(path).ok().and_then(|content| Self::parse(&content))
+    }
+}
+
+pub fn find_lockfile() -> Option<Lockfile> {
+    find_lockfile_in(&riot_client::league_install_dirs())
+}
+
+pub fn find_lockfile_in(install_dirs: &[PathBuf]) -> Option<Lockfile> {
+    install_dirs
+        .iter()
+        .find_map(|dir| Lockfile::read(&dir.join("lockfile")))
+}
+
+// The Riot Client serves its own API, next to the League client's, while it is running.
+pub fn find_riot_client_lockfile() -> Option<Lockfile> {
+    let local_app_data = std::env::var_os("LOCALAPPDATA")?;
+    Lockfile::read(
+        &Path::new(&local_app_data)
+            .join("Riot Games")
+            .join("Riot Client")
+            .join("Config")
+            .join("lockfile"),
+    )
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Summoner {
+    pub puuid: String,
+    pub summoner_id: u64,
+    pub account_id: u64,
+    pub game_name: String,
+    pub tag_line: String,
+    pub display_name: String,
+    pub summoner_level: u32,
+    pub profile_icon_id: u32,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameflowPhase {
+    None,
+    Lobby,
+    Matchmaking,
+    CheckedIntoTournament,
+    ReadyCheck,
+    ChampSelect,
+    GameStart,
+    FailedToLaunch,
+    InProgress,
+    Reconnect,
+    WaitingForStats,
+    PreEndOfGame,
+    EndOfGame,
+    TerminatedInError,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RankedQueueStats {
+    pub queue_type: String,
+    pub tier: String,
+    pub division: String,
+    pub league_points: i32,
+    pub wins: u32,
+    pub losses: u32,
+    pub is_provisional: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RankedStats {
+    pub queues: Vec<RankedQueueStats>,
+}
+
+impl RankedStats {
+    pub fn queue(&self, queue_type: &str) -> Option<&RankedQueueStats> {
+        self.queues.iter().find(|q| q.queue_type == queue_type)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchParticipantStats {
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub win: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchParticipant {
+    pub champion_id: i64,
+    pub stats: MatchParticipantStats,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchHistoryGame {
+    pub game_id: u64,
+    pub game_creation: u64,
+    pub game_duration: u64,
+    pub queue_id: i32,
+    pub participants: Vec<MatchParticipant>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+struct MatchHistoryGames {
+    games: Vec<MatchHistoryGame>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+struct MatchHistory {
+    games: MatchHistoryGames,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectPlayer {
+    pub cell_id: i64,
+    pub assigned_position: String,
+    pub champion_id: i64,
+    pub champion_pick_intent: i64,
+    pub spell1_id: i64,
+    pub spell2_id: i64,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectAction {
+    pub id: i64,
+    pub actor_cell_id: i64,
+    pub champion_id: i64,
+    pub completed: bool,
+    pub is_in_progress: bool,
+    #[serde(rename = "type")]
+    pub action_type: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ChampSelectSession {
+    pub local_player_cell_id: i64,
+    pub my_team: Vec<ChampSelectPlayer>,
+    pub actions: Vec<Vec<ChampSelectAction>>,
+}
+
+impl ChampSelectSession {
+    pub fn local_player(&self) -> Option<&ChampSelectPlayer> {
+        self.my_team.iter().find(|p| p.cell_id == self.local_player_cell_id)
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct ChampionSummary {
+    pub id: i64,
+    pub name: String,
+    pub alias: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RunePage {
+    pub id: i64,
+    pub name: String,
+    pub primary_style_id: i64,
+    pub sub_style_id: i64,
+    pub selected_perk_ids: Vec<i64>,
+    pub is_deletable: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ReformCard {
+    pub punishment_type: String,
+    pub time_when_punishment_expires: u64,
+    pub restricted_chat_games_remaining: i32,
+}
+
+// The Riot Client returns the account's user info as JSON inside a string.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct UserInfoResponse {
+    user_info: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct RiotUserInfo {
+    pub ban: AccountBans,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct AccountBans {
+    pub restrictions: Vec<AccountBan>,
+}
+
+// `kind` is e.g. PERMANENT_BAN or TIME_BAN; `scope` names the game, or riot for the whole account.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct AccountBan {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub scope: String,
+    pub reason: String,
+    pub dat: AccountBanData,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AccountBanData {
+    pub expiration_millis: Option<u64>,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RankedRestriction {
+    pub punished_games_remaining: u32,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchmakingSearchError {
+    pub error_type: String,
+    pub penalty_time_remaining: f64,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LowPriorityData {
+    pub penalty_time_remaining: f64,
+    pub reason: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MatchmakingSearch {
+    pub errors: Vec<MatchmakingSearchError>,
+    pub low_priority_data: LowPriorityData,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LootItem {
+    pub loot_id: String,
+    pub count: i64,
+    #[serde(rename = "type")]
+    pub loot_type: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct ChampionOwnership {
+    pub owned: bool,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct OwnedChampion {
+    pub id: i64,
+    pub ownership: ChampionOwnership,
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HonorProfile {
+    pub honor_level: u32,
+}
+
+pub struct LcuClient {
+    agent: ureq::Agent,
+    base_url: String,
+    auth_header: String,
+}
+
+impl LcuClient {
+    pub fn connect() -> Result<Self, String> {
+        find_lockfile()
+            .map(|lockfile| Self::from_lockfile(&lockfile))
+            .ok_or_else(|| t!("lcu.not_running").to_owned())
+    }
+
+    pub fn from_lockfile(lockfile: &Lockfile) -> Self {
+        // The client serves HTTPS on 127.0.0.1 with a certificate signed by Riot's own root.
+        static RIOT_TLS: OnceLock<Arc<rustls::ClientConfig>> = OnceLock::new();
+        let tls = RIOT_TLS.get_or_init(|| {
+            lcu_tls::client_config(lcu_tls::RIOT_ROOT_PEM).expect("riotgames.pem holds a valid certificate")
+        });
+        Self::with_tls(lockfile, tls.clone())
+    }
+
+    fn with_tls(lockfile: &Lockfile, tls: Arc<rustls::ClientConfig>) -> Self {
+        let config = ureq::Agent::config_builder().timeout_global(Some(REQUEST_TIMEOUT)).build();
+        let connector = ().chain(TcpConnector::default()).chain(lcu_tls::PinnedTlsConnector(tls));
+        let agent = ureq::Agent::with_parts(config, connector, DefaultResolver::default());
+
+        let credentials = STANDARD.encode(format!("riot:{}", lockfile.password));
+
+        Self {
+            agent,
+            base_url: format!("{}://127.0.0.1:{}", lockfile.protocol, lockfile.port),
+            auth_header: format!("Basic {}", credentials),
+        }
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}{}", self.base_url, endpoint)
+    }
+
+    pub fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T, String> {
+        self.agent
+            .get(self.url(endpoint))
+            .header("Authorization", &self.auth_header)
+            .header("Accept", "application/json")
+            .call()
+            .and_then(|mut response| response.body_mut().read_json::<T>())
+            .map_err(|e| t!("lcu.request_failed", method = "GET", endpoint = endpoint, error = e))
+    }
+
+    pub fn post(&self, endpoint: &str) -> Result<(), String> {
+        self.agent
+            .post(self.url(endpoint))
+            .header("Authorization", &self.auth_header)
+            .send_empty()
+            .map(|_| ())
+            .map_err(|e| t!("lcu.request_failed", method = "POST", endpoint = endpoint, error = e))
+    }
+
+    pub fn post_json<B: Serialize>(&self, endpoint: &str, body: &B) -> Result<(), String> {
+        self.agent
+            .post(self.url(endpoint))
+            .header("Authorization", &self.auth_header)
+            .send_json(body)
+            .map(|_| ())
+            .map_err(|e| t!("lcu.request_failed", method = "POST", endpoint = endpoint, error = e))
+    }
+
+    pub fn delete(&self, endpoint: &str) -> Result<(), String> {
+        self.agent
+            .delete(self.url(endpoint))
+            .header("Authorization", &self.auth_header)
+            .call()
+            .map(|_| ())
+            .map_err(|e| t!("lcu.request_failed", method = "DELETE", endpoint = endpoint, error = e))
+    }
+
+    pub fn current_summoner(&self) -> Result<Summoner, String> {
+        self.get("/lol-summoner/v1/current-summoner")
+    }
+
+    pub fn gameflow_phase(&self) -> Result<GameflowPhase, String> {
+        self.get("/lol-gameflow/v1/gameflow-phase")
+    }
+
+    pub fn ranked_stats(&self) -> Result<RankedStats, String> {
+        self.get("/lol-ranked/v1/current-ranked-stats")
+    }
+
+    pub fn patch<B: Serialize>(&self, endpoint: &str, body: &B) -> Result<(), String> {
+        self.agent
+            .patch(self.url(endpoint))
+            .header("Authorization", &self.auth_header)
+            .send_json(body)
+            .map(|_| ())
+            .map_err(|e| t!("lcu.request_failed", method = "PATCH", endpoint = endpoint, error = e))
+    }
+
+    pub fn accept_ready_check(&self) -> Result<(), String> {
+        self.post("/lol-matchmaking/v1/ready-check/accept")
+    }
+
+    pub fn champ_select_session(&self) -> Result<ChampSelectSession, String> {
+        self.get("/lol-champ-select/v1/session")
+    }
+
+    pub fn pickable_champion_ids(&self) -> Result<Vec<i64>, String> {
+        self.get("/lol-champ-select/v1/pickable-champion-ids")
+    }
+
+    pub fn bannable_champion_ids(&self) -> Result<Vec<i64>, String> {
+        self.get("/lol-champ-select/v1/bannable-champion-ids")
+    }
+
+    pub fn champion_summaries(&self) -> Result<Vec<ChampionSummary>, String> {
+        self.get("/lol-game-data/assets/v1/champion-summary.json")
+    }
+
+    pub fn hover_champion(&self, action_id: i64, champion_id: i64) -> Result<(), String> {
+        let endpoint = format!("/lol-champ-select/v1/session/actions/{}", action_id);
+        self.patch(&endpoint, &serde_json::json!({ "championId": champion_id }))
+    }
+
+    pub fn complete_action(&self, action_id: i64) -> Result<(), String> {
+        self.post(&format!("/lol-champ-select/v1/session/actions/{}/complete", action_id))
+    }
+
+    pub fn set_summoner_spells(&self, spell1_id: i64, spell2_id: i64) -> Result<(), String> {
+        self.patch(
+            "/lol-champ-select/v1/session/my-selection",
+            &serde_json::json!({ "spell1Id": spell1_id, "spell2Id": spell2_id }),
+        )
+    }
+
+    pub fn current_rune_page(&self) -> Result<RunePage, String> {
+        self.get("/lol-perks/v1/currentpage")
+    }
+
+    pub fn rune_pages(&self) -> Result<Vec<RunePage>, String> {
+        self.get("/lol-perks/v1/pages")
+    }
+
+    pub fn delete_rune_page(&self, id: i64) -> Result<(), String> {
+        self.delete(&format!("/lol-perks/v1/pages/{}", id))
+    }
+
+    pub fn create_rune_page(
+        &self,
+        name: &str,
+        primary_style_id: i64,
+        sub_style_id: i64,
+        selected_perk_ids: &[i64],
+    ) -> Result<(), String> {
+        self.post_json(
+            "/lol-perks/v1/pages",
+            &serde_json::json!({
+                "name": name,
+                "primaryStyleId": primary_style_id,
+                "subStyleId": sub_style_id,
+                "selectedPerkIds": selected_perk_ids,
+                "current": true,
+            }),
+        )
+    }
+
+    pub fn reform_card(&self) -> Result<ReformCard, String> {
+        self.get("/lol-player-behavior/v3/reform-card")
+    }
+
+    // Riot Client API only: the signed-in account, including its bans.
+    pub fn riot_user_info(&self) -> Result<RiotUserInfo, String> {
+        let response: UserInfoResponse = self.get("/rso-auth/v1/authorization/userinfo")?;
+        serde_json::from_str(&response.user_info).map_err(|e| e.to_string())
+    }
+
+    pub fn ranked_restriction(&self) -> Result<RankedRestriction, String> {
+        self.get("/lol-player-behavior/v1/ranked-restriction")
+    }
+
+    pub fn matchmaking_search(&self) -> Result<MatchmakingSearch, String> {
+        self.get("/lol-matchmaking/v1/search")
+    }
+
+    pub fn player_loot(&self) -> Result<Vec<LootItem>, String> {
+        self.get("/lol-loot/v1/player-loot")
+    }
+
+    pub fn owned_champions(&self) -> Result<Vec<OwnedChampion>, String> {
+        self.get("/lol-champions/v1/owned-champions-minimal")
+    }
+
+    pub fn honor_profile(&self) -> Result<HonorProfile, String> {
+        self.get("/lol-honor-v2/v1/profile")
+    }
+
+    pub fn recent_matches(&self, count: u32) -> Result<Vec<MatchHistoryGame>, String> {
+        let endpoint = format!(
+            "/lol-match-history/v1/products/lol/current-summoner/matches?begIndex=0&endIndex={}",
+            count
+        );
+        self.get::<MatchHistory>(&endpoint).map(|history| history.games.games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+    use rustls::pki_types::pem::PemObject;
+    use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    // Stands in for Riot's root: it signed the stub's certificate, which like the real one
+    // names 127.0.0.1 only in its common name.
+    const TEST_ROOT_PEM: &[u8] = include_bytes!("../tests/fixtures/lcu_ca.pem");
+
+    const PASSWORD: &str = "stub-password";
+
+    // Serves canned JSON for the endpoints the client uses, over plain HTTP.
+    fn start_stub_server() -> u16 {
+        serve_stub(respond)
+    }
+
+    // Same endpoints over HTTPS, with a certificate signed by the test root.
+    fn start_tls_stub_server() -> u16 {
+        let cert = CertificateDer::from_pem_slice(include_bytes!("../tests/fixtures/lcu_cert.pem")).unwrap();
+        let key = PrivateKeyDer::from_pem_slice(include_bytes!("../tests/fixtures/lcu_key.pem")).unwrap();
+        let config = rustls::ServerConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .unwrap()
+            .with_no_client_auth()
+            .with_single_cert(vec![cert], key)
+            .unwrap();
+        let config = Arc::new(config);
+
+        serve_stub(move |stream| {
+            let connection = rustls::ServerConnection::new(config.clone()).unwrap();
+            respond(rustls::StreamOwned::new(connection, stream));
+        })
+    }
+
+    fn serve_stub(handle: impl Fn(TcpStream) + Send + 'static) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                handle(stream);
+            }
+        });
+
+        port
+    }
+
+    fn respond(stream: impl Read + Write) {
+        let mut reader = BufReader::new(stream);
+
+        let mut request_line = String::new();
+        if reader.read_line(&mut request_line).is_err() {
+            return;
+        }
+        let path = request_line.split_whitespace().nth(1).unwrap_or("").to_owned();
+
+        let mut authorized = false;
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+                break;
+            }
+            let expected = format!("Basic {}", STANDARD.encode(format!("riot:{}", PASSWORD)));
+            if header.to_ascii_lowercase().starts_with("authorization:")
+                && header.trim_end().ends_with(&expected)
+            {
+                authorized = true;
+            }
+        }
+
+        let (status, body) = if !authorized {
+            ("401 Unauthorized", String::new())
+        } else {
+            match path.as_str() {
+                "/lol-summoner/v1/current-summoner" => (
+                    "200 OK",
+                    r#"{"gameName":"Stub","tagLine":"EUNE","summonerLevel":312,"puuid":"abc","summonerId":42}"#.to_owned(),
+                ),
+                "/lol-gameflow/v1/gameflow-phase" => ("200 OK", r#""ReadyCheck""#.to_owned()),
+                "/lol-ranked/v1/current-ranked-stats" => (
+                    "200 OK",
+                    r#"{"queues":[{"queueType":"RANKED_SOLO_5x5","tier":"DIAMOND","division":"II","leaguePoints":57,"wins":120,"losses":101}]}"#.to_owned(),
+                ),
+                _ => ("404 Not Found", String::new()),
+            }
+        };
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let stream = reader.get_mut();
+        let _ = stream.write_all(response.as_bytes());
+        let _ = stream.flush();
+    }
+
+    fn stub_client() -> LcuClient {
+        let port = start_stub_server();
+        let dir = TempDir::new("lcu");
+        let path = dir.path().join("lockfile");
+        fs::write(&path, format!("LeagueClient:1234:{}:{}:http", port, PASSWORD)).unwrap();
+
+        let lockfile = Lockfile::read(&path).expect("stub lockfile should parse");
+        LcuClient::from_lockfile(&lockfile)
+    }
+
+    #[test]
+    fn parses_lockfile() {
+        let lockfile = Lockfile::parse("LeagueClient:17748:61234:Zx9-abc_DEF:https\n").unwrap();
+        assert_eq!(lockfile.process, "LeagueClient");
+        assert_eq!(lockfile.pid, 17748);
+        assert_eq!(lockfile.port, 61234);
+        assert_eq!(lockfile.password, "Zx9-abc_DEF");
+        assert_eq!(lockfile.protocol, "https");
+
+        assert!(Lockfile::parse("LeagueClient:17748:notaport:pw:https").is_none());
+        assert!(Lockfile::parse("").is_none());
+    }
+
+    #[test]
+    fn reads_typed_endpoints_from_stub() {
+        let client = stub_client();
+
+        let summoner = client.current_summoner().unwrap();
+        assert_eq!(summoner.game_name, "Stub");
+        assert_eq!(summoner.tag_line, "EUNE");
+        assert_eq!(summoner.summoner_level, 312);
+
+        assert_eq!(client.gameflow_phase().unwrap(), GameflowPhase::ReadyCheck);
+
+        let ranked = client.ranked_stats().unwrap();
+        let solo = ranked.queue("RANKED_SOLO_5x5").unwrap();
+        assert_eq!(solo.tier, "DIAMOND");
+        assert_eq!(solo.division, "II");
+        assert_eq!(solo.league_points, 57);
+        assert!(ranked.queue("RANKED_FLEX_SR").is_none());
+    }
+
+    #[test]
+    fn reports_rejected_password() {
+        let port = start_stub_server();
+        let lockfile = Lockfile {
+            process: "LeagueClient".into(),
+            pid: 1,
+            port,
+            password: "wrong".into(),
+            protocol: "http".into(),
+        };
+
+        let error = LcuClient::from_lockfile(&lockfile).current_summoner().unwrap_err();
+        assert!(error.contains("/lol-summoner/v1/current-summoner"));
+    }
+
+    fn tls_lockfile() -> Lockfile {
+        Lockfile {
+            process: "LeagueClient".into(),
+            pid: 1,
+            port: start_tls_stub_server(),
+            password: PASSWORD.into(),
+            protocol: "https".into(),
+        }
+    }
+
+    #[test]
+    fn accepts_certificates_signed_by_the_pinned_root() {
+        let tls = lcu_tls::client_config(TEST_ROOT_PEM).unwrap();
+        let summoner = LcuClient::with_tls(&tls_lockfile(), tls).current_summoner().unwrap();
+        assert_eq!(summoner.game_name, "Stub");
+        assert_eq!(summoner.puuid, "abc");
+    }
+
+    #[test]
+    fn rejects_certificates_riot_did_not_sign() {
+        assert!(LcuClient::from_lockfile(&tls_lockfile()).current_summoner().is_err());
+    }
+
+    #[test]
+    fn riot_root_is_a_valid_trust_anchor() {
+        assert!(lcu_tls::client_config(lcu_tls::RIOT_ROOT_PEM).is_ok());
+    }
+}
@@ -4,6 +4,24 @@ mod app;
 mod launcher;
 mod credentials;
 mod settings;
+mod riot_client;
+mod lcu;
+mod lcu_tls;
+mod decay;
+mod notifications;
+mod client_watcher;
+mod champ_select;
+mod presets;
+mod restrictions;
+mod wallet;
+mod match_history;
+mod trash;
+mod i18n;
+mod theme;
+mod widgets;
+mod settings_page;
+#[cfg(test)]
+mod test_support;
 
 use app::RustyLeagueApp;
 use eframe::egui;
@@ -21,13 +39,23 @@ fn main() -> eframe::Result<()> {
         height,
     };
 
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size([800.0, 600.0])
+        .with_min_inner_size([300.0, 220.0])
+        .with_icon(Arc::new(icon_data));
+    if let Some(window) = settings::load_settings().window {
+        viewport = viewport
+            .with_inner_size([window.width.max(300.0), window.height.max(220.0)])
+            .with_maximized(window.maximized);
+        // A monitor unplugged since the last run would open the window off screen,
+        // so Windows gets to place it instead.
+        if is_on_a_monitor(&window) {
+            viewport = viewport.with_position([window.x, window.y]);
+        }
+    }
+
     let simple_options = eframe::NativeOptions {
-        viewport: egui::ViewportBuilder::default()
-            .with_inner_size([800.0, 600.0])
-            .with_min_inner_size([300.0, 220.0])
-            .with_resizable(false)
-            .with_maximize_button(false)
-            .with_icon(Arc::new(icon_data)),
+        viewport,
         ..Default::default()
     };
 
@@ -37,3 +65,25 @@ fn main() -> eframe::Result<()> {
         Box::new(|cc| Ok(Box::new(RustyLeagueApp::new(cc)))),
     )
 }
+
+fn is_on_a_monitor(window: &settings::WindowGeometry) -> bool {
+    #[repr(C)]
+    struct Rect {
+        left: i32,
+        top: i32,
+        right: i32,
+        bottom: i32,
+    }
+    const MONITOR_DEFAULTTONULL: u32 = 0;
+
+    #[link(name = "user32")]
+    unsafe extern "system" {
+        fn MonitorFromRect(rect: *const Rect, flags: u32) -> *mut std::ffi::c_void;
+    }
+
+    let [left, top, right, bottom] = window.physical_bounds();
+    let rect = Rect { left, top, right, bottom };
+    // SAFETY: MonitorFromRect only reads the rectangle, which outlives the call.
+    let monitor = unsafe { MonitorFromRect(&rect, MONITOR_DEFAULTTONULL) };
+    !monitor.is_null()
+}
@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use directories::ProjectDirs;
+use crate::champ_select::ChampionIndex;
+use crate::i18n::t;
+use crate::lcu::MatchHistoryGame;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct StoredMatch {
+    pub game_id: u64,
+    pub played_at: u64,
+    pub queue_id: i32,
+    pub champion_id: i64,
+    pub champion: String,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub win: bool,
+    pub duration_secs: u64,
+}
+
+impl StoredMatch {
+    pub fn kda(&self) -> f32 {
+        (self.kills + self.assists) as f32 / self.deaths.max(1) as f32
+    }
+}
+
+pub fn queue_name(queue_id: i32) -> String {
+    match queue_id {
+        400 => t!("queue.normal_draft").to_owned(),
+        420 => t!("queue.ranked_solo").to_owned(),
+        430 => t!("queue.normal_blind").to_owned(),
+        440 => t!("queue.ranked_flex").to_owned(),
+        450 => t!("queue.aram").to_owned(),
+        490 => t!("queue.quickplay").to_owned(),
+        700 => t!("queue.clash").to_owned(),
+        830 | 840 | 850 | 870 | 880 | 890 => t!("queue.coop").to_owned(),
+        900 | 1010 => t!("queue.arurf").to_owned(),
+        1700 | 1710 => t!("queue.arena").to_owned(),
+        1900 => t!("queue.urf").to_owned(),
+        0 => t!("queue.custom").to_owned(),
+        other => t!("queue.other", id = other),
+    }
+}
+
+// The current-summoner endpoint only lists the signed-in player among the participants.
+pub fn from_games(games: &[MatchHistoryGame], index: Option<&ChampionIndex>) -> Vec<StoredMatch> {
+    games
+        .iter()
+        .filter_map(|game| {
+            let player = game.participants.first()?;
+            Some(StoredMatch {
+                game_id: game.game_id,
+                played_at: game.game_creation / 1000,
+                queue_id: game.queue_id,
+                champion_id: player.champion_id,
+                champion: index.map(|index| index.name(player.champion_id)).unwrap_or_default(),
+                kills: player.stats.kills,
+                deaths: player.stats.deaths,
+                assists: player.stats.assists,
+                win: player.stats.win,
+                duration_secs: game.game_duration,
+            })
+        })
+        .collect()
+}
+
+// One file per account, named by the hex-encoded login username so that no two
+// usernames can share a file.
+fn history_path(username: &str) -> Option<PathBuf> {
+    let proj_dirs = ProjectDirs::from("pl", "Rusty Credentials", "")?;
+    Some(proj_dirs.config_dir().join("match_history").join(file_name(username)))
+}
+
+fn file_name(username: &str) -> String {
+    let hex: String = username.bytes().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}.jsonl", hex)
+}
+
+// Earlier versions replaced unsupported characters with `_`, which made some usernames collide.
+fn legacy_file_name(username: &str) -> String {
+    let sanitized: String = username
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    format!("{}.jsonl", sanitized)
+}
+
+// Moves a history saved under the old file name to the current one.
+fn migrate_legacy_file(username: &str, path: &Path) {
+    let legacy = path.with_file_name(legacy_file_name(username));
+    if !path.exists() && legacy.exists() {
+        let _ = fs::rename(legacy, path);
+    }
+}
+
+// Newest first.
+pub fn load(username: &str) -> Vec<StoredMatch> {
+    let Some(path) = history_path(username) else {
+        return Vec::new();
+    };
+    migrate_legacy_file(username, &path);
+    load_file(&path)
+}
+
+fn load_file(path: &Path) -> Vec<StoredMatch> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    let mut matches: Vec<StoredMatch> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    matches.sort_by_key(|m| std::cmp::Reverse(m.played_at));
+    matches
+}
+
+// Appends the games that are not stored yet and returns how many were added.
+// Existing lines are never rewritten.
+pub fn append(username: &str, matches: &[StoredMatch]) -> std::io::Result<usize> {
+    let path = history_path(username)
+        .ok_or_else(|| std::io::Error::other("Could not determine config directory"))?;
+    migrate_legacy_file(username, &path);
+    append_to_file(&path, matches)
+}
+
+fn append_to_file(path: &Path, matches: &[StoredMatch]) -> std::io::Result<usize> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut known: HashSet<u64> = load_file(path).iter().map(|m| m.game_id).collect();
+    let mut lines = String::new();
+    let mut added = 0;
+    for stored in matches {
+        if known.insert(stored.game_id) {
+            lines.push_str(&serde_json::to_string(stored)?);
+            lines.push('\n');
+            added += 1;
+        }
+    }
+
+    if added > 0 {
+        OpenOptions::new().create(true).append(true).open(path)?.write_all(lines.as_bytes())?;
+    }
+    Ok(added)
+}
+
+// Moves the stored games along when an account's username changes.
+pub fn rename(old_username: &str, new_username: &str) -> std::io::Result<()> {
+    if old_username == new_username {
+        return Ok(());
+    }
+    let (Some(from), Some(to)) = (history_path(old_username), history_path(new_username)) else {
+        return Ok(());
+    };
+    migrate_legacy_file(old_username, &from);
+    migrate_legacy_file(new_username, &to);
+    move_file(&from, &to)
+}
+
+// Games already stored at `to` are kept; the ones from `from` are added to them.
+fn move_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    if !from.exists() {
+        return Ok(());
+    }
+    if to.exists() {
+        append_to_file(to, &load_file(from))?;
+        fs::remove_file(from)
+    } else {
+        fs::rename(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    fn game(game_id: u64) -> StoredMatch {
+        StoredMatch {
+            game_id,
+            played_at: game_id,
+            queue_id: 420,
+            champion_id: 103,
+            champion: "Ahri".to_owned(),
+            kills: 5,
+            deaths: 2,
+            assists: 7,
+            win: true,
+            duration_secs: 1800,
+        }
+    }
+
+    fn ids(path: &Path) -> Vec<u64> {
+        load_file(path).iter().map(|m| m.game_id).collect()
+    }
+
+    #[test]
+    fn file_names_never_collide() {
+        assert_ne!(file_name("john.doe"), file_name("john_doe"));
+        assert_ne!(file_name("ąę"), file_name("__"));
+        assert_eq!(file_name("Ab1"), "416231.jsonl");
+        assert_eq!(legacy_file_name("john.doe"), legacy_file_name("john_doe"));
+    }
+
+    #[test]
+    fn moving_a_history_renames_its_file() {
+        let dir = TempDir::new("history");
+        let from = dir.path().join(file_name("old"));
+        let to = dir.path().join(file_name("new"));
+        append_to_file(&from, &[game(1), game(2)]).unwrap();
+
+        move_file(&from, &to).unwrap();
+        assert!(!from.exists());
+        assert_eq!(ids(&to), vec![2, 1]);
+    }
+
+    #[test]
+    fn moving_onto_an_existing_history_merges_them() {
+        let dir = TempDir::new("history");
+        let from = dir.path().join(file_name("old"));
+        let to = dir.path().join(file_name("new"));
+        append_to_file(&from, &[game(1), game(2)]).unwrap();
+        append_to_file(&to, &[game(2), game(3)]).unwrap();
+
+        move_file(&from, &to).unwrap();
+        assert!(!from.exists());
+        assert_eq!(ids(&to), vec![3, 2, 1]);
+    }
+}
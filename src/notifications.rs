@@ -0,0 +1,5 @@
+use notify_rust::Notification;
+
+pub fn show(summary: &str, body: &str) -> Result<(), String> {
+    Notification::new().summary(summary).body(body).show().map(|_| ()).map_err(|e| e.to_string())
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use crate::champ_select::{ANY_ROLE, ChampionIndex};
+use crate::i18n::t;
+
+// Rune pages created by the app are recognised by this prefix and replaced on the next apply.
+pub const RUNE_PAGE_PREFIX: &str = "Rusty League";
+
+pub const SUMMONER_SPELLS: [i64; 11] = [4, 14, 12, 11, 7, 3, 21, 6, 1, 13, 32];
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct RunePreset {
+    pub primary_style_id: i64,
+    pub sub_style_id: i64,
+    pub selected_perk_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct LoadoutPreset {
+    pub name: String,
+    pub champion: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub runes: Option<RunePreset>,
+    // 0 leaves the spell selected in the client unchanged.
+    #[serde(default)]
+    pub spell1_id: i64,
+    #[serde(default)]
+    pub spell2_id: i64,
+}
+
+impl LoadoutPreset {
+    pub fn has_spells(&self) -> bool {
+        self.spell1_id > 0 && self.spell2_id > 0
+    }
+
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.runes.is_some() {
+            parts.push(t!("presets.summary_runes").to_owned());
+        }
+        if self.has_spells() {
+            parts.push(format!("{} + {}", spell_name(self.spell1_id), spell_name(self.spell2_id)));
+        }
+        if parts.is_empty() {
+            t!("presets.summary_empty").to_owned()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+pub fn spell_name(id: i64) -> &'static str {
+    match id {
+        4 => t!("spell.flash"),
+        14 => t!("spell.ignite"),
+        12 => t!("spell.teleport"),
+        11 => t!("spell.smite"),
+        7 => t!("spell.heal"),
+        3 => t!("spell.exhaust"),
+        21 => t!("spell.barrier"),
+        6 => t!("spell.ghost"),
+        1 => t!("spell.cleanse"),
+        13 => t!("spell.clarity"),
+        32 => t!("spell.mark"),
+        _ => t!("common.none"),
+    }
+}
+
+// A preset for the assigned role wins over one saved for any role.
+pub fn find<'a>(
+    presets: &'a [LoadoutPreset],
+    index: &ChampionIndex,
+    champion_id: i64,
+    role: &str,
+) -> Option<&'a LoadoutPreset> {
+    let for_champion: Vec<&LoadoutPreset> = presets
+        .iter()
+        .filter(|preset| index.id(&preset.champion) == Some(champion_id))
+        .collect();
+
+    for_champion
+        .iter()
+        .find(|preset| !role.is_empty() && preset.role == role)
+        .or_else(|| for_champion.iter().find(|preset| preset.role.is_empty() || preset.role == ANY_ROLE))
+        .copied()
+}
@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use crate::credentials;
+use crate::i18n::{self, t};
+use crate::lcu::{LcuClient, RiotUserInfo};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RestrictionKind {
+    PermanentBan,
+    Suspension,
+    LeaverBuster,
+    RankedRestriction,
+    ChatRestriction,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Restriction {
+    pub kind: RestrictionKind,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    #[serde(default)]
+    pub games_remaining: Option<u32>,
+    #[serde(default)]
+    pub detail: String,
+}
+
+impl Restriction {
+    fn new(kind: RestrictionKind) -> Self {
+        Self { kind, expires_at: None, games_remaining: None, detail: String::new() }
+    }
+
+    // Game-based restrictions have no date and stay until the next login says otherwise.
+    pub fn is_active(&self, now: u64) -> bool {
+        self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+
+    // The account cannot be played at all.
+    pub fn is_blocking(&self) -> bool {
+        matches!(self.kind, RestrictionKind::PermanentBan | RestrictionKind::Suspension)
+    }
+
+    pub fn badge(&self) -> String {
+        let name = match self.kind {
+            RestrictionKind::PermanentBan => return t!("restriction.banned").to_owned(),
+            RestrictionKind::Suspension => t!("restriction.suspended"),
+            RestrictionKind::LeaverBuster => t!("restriction.leaver_buster"),
+            RestrictionKind::RankedRestriction => t!("restriction.ranked"),
+            RestrictionKind::ChatRestriction => t!("restriction.chat"),
+        };
+
+        match (self.expires_at, self.games_remaining) {
+            (Some(expires_at), _) => t!("restriction.until", name = name, date = format_date(expires_at)),
+            (None, Some(games)) => match i18n::plural(u64::from(games)) {
+                "one" => t!("restriction.games.one", name = name),
+                "few" => t!("restriction.games.few", name = name, games = games),
+                _ => t!("restriction.games.many", name = name, games = games),
+            },
+            (None, None) => name.to_owned(),
+        }
+    }
+}
+
+// The most severe restriction that is still in effect.
+pub fn most_severe(restrictions: &[Restriction], now: u64) -> Option<&Restriction> {
+    restrictions.iter().filter(|r| r.is_active(now)).min_by_key(|r| r.kind)
+}
+
+// Fails when the reform card, which reports bans and suspensions, cannot be read; the
+// other endpoints only exist while their restriction applies.
+pub fn from_client(client: &LcuClient) -> Result<Vec<Restriction>, String> {
+    let now = credentials::unix_now();
+    let mut found = Vec::new();
+
+    let card = client.reform_card()?;
+    let punishment = card.punishment_type.to_ascii_uppercase();
+    let kind = if punishment.contains("PERMA") {
+        Some(RestrictionKind::PermanentBan)
+    } else if punishment.contains("BAN") {
+        Some(RestrictionKind::Suspension)
+    } else if punishment.contains("RANKED") {
+        Some(RestrictionKind::RankedRestriction)
+    } else if punishment.contains("CHAT") {
+        Some(RestrictionKind::ChatRestriction)
+    } else {
+        None
+    };
+
+    if let Some(kind) = kind {
+        let mut restriction = Restriction::new(kind);
+        restriction.detail = card.punishment_type.clone();
+        if card.time_when_punishment_expires > 0 {
+            restriction.expires_at = Some(card.time_when_punishment_expires / 1000);
+        }
+        if kind == RestrictionKind::ChatRestriction && card.restricted_chat_games_remaining > 0 {
+            restriction.games_remaining = Some(card.restricted_chat_games_remaining as u32);
+        }
+        found.push(restriction);
+    }
+
+    if let Ok(ranked) = client.ranked_restriction()
+        && ranked.punished_games_remaining > 0
+        && !found.iter().any(|r| r.kind == RestrictionKind::RankedRestriction)
+    {
+        let mut restriction = Restriction::new(RestrictionKind::RankedRestriction);
+        restriction.games_remaining = Some(ranked.punished_games_remaining);
+        found.push(restriction);
+    }
+
+    // Only reported while a queue has been tried, otherwise the endpoint returns 404.
+    if let Ok(search) = client.matchmaking_search() {
+        let remaining = search
+            .errors
+            .iter()
+            .map(|error| error.penalty_time_remaining)
+            .chain([search.low_priority_data.penalty_time_remaining])
+            .fold(0.0_f64, f64::max);
+        if remaining > 0.0 {
+            let mut restriction = Restriction::new(RestrictionKind::LeaverBuster);
+            restriction.expires_at = Some(now + remaining.ceil() as u64);
+            restriction.detail = search.low_priority_data.reason.clone();
+            found.push(restriction);
+        }
+    }
+
+    Ok(found)
+}
+
+// Bans the Riot Client reports for the signed-in account, which keep it from reaching
+// League. Read from structured fields, so the client language does not matter.
+pub fn from_account_bans(info: &RiotUserInfo, now: u64) -> Vec<Restriction> {
+    info.ban
+        .restrictions
+        .iter()
+        .filter(|ban| {
+            ban.scope.is_empty() || ban.scope.eq_ignore_ascii_case("lol") || ban.scope.eq_ignore_ascii_case("riot")
+        })
+        .filter_map(|ban| {
+            let kind = match ban.kind.to_ascii_uppercase().as_str() {
+                "PERMANENT_BAN" => RestrictionKind::PermanentBan,
+                "TIME_BAN" => RestrictionKind::Suspension,
+                _ => return None,
+            };
+            let mut restriction = Restriction::new(kind);
+            if kind == RestrictionKind::Suspension {
+                restriction.expires_at = ban.dat.expiration_millis.map(|millis| millis / 1000);
+            }
+            restriction.detail = ban.reason.clone();
+            Some(restriction)
+        })
+        .filter(|restriction| restriction.is_active(now))
+        .collect()
+}
+
+// YYYY-MM-DD in UTC, from days since the epoch (Howard Hinnant's civil_from_days).
+pub fn format_date(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_dates_in_utc() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_709_251_199), "2024-02-29");
+        assert_eq!(format_date(1_735_689_600), "2025-01-01");
+    }
+
+    fn user_info(json: &str) -> RiotUserInfo {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn reads_account_bans() {
+        let info = user_info(
+            r#"{"sub":"abc","ban":{"restrictions":[
+                {"type":"TIME_BAN","reason":"INAPPROPRIATE_TEXT","scope":"lol","dat":{"expirationMillis":1735689600000}},
+                {"type":"TEXT_CHAT_RESTRICTION","scope":"lol","dat":{}},
+                {"type":"PERMANENT_BAN","reason":"CHEATING","scope":"valorant","dat":{}}
+            ]}}"#,
+        );
+        let found = from_account_bans(&info, 1_700_000_000);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, RestrictionKind::Suspension);
+        assert_eq!(found[0].expires_at, Some(1_735_689_600));
+        assert_eq!(found[0].detail, "INAPPROPRIATE_TEXT");
+    }
+
+    #[test]
+    fn reads_account_wide_permanent_bans() {
+        let info = user_info(r#"{"ban":{"restrictions":[{"type":"PERMANENT_BAN","scope":"riot","dat":{}}]}}"#);
+        let found = from_account_bans(&info, 1_700_000_000);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].kind, RestrictionKind::PermanentBan);
+        assert_eq!(found[0].expires_at, None);
+    }
+
+    #[test]
+    fn ignores_expired_bans_and_accounts_without_any() {
+        let expired = user_info(
+            r#"{"ban":{"restrictions":[{"type":"TIME_BAN","scope":"lol","dat":{"expirationMillis":1000}}]}}"#,
+        );
+        assert!(from_account_bans(&expired, 1_700_000_000).is_empty());
+        assert!(from_account_bans(&user_info(r#"{"sub":"abc"}"#), 1_700_000_000).is_empty());
+    }
+}
@@ -0,0 +1,540 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::os::windows::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock, mpsc};
+use std::time::SystemTime;
+use std::thread;
+use crate::i18n::t;
+
+pub const RIOT_CLIENT_EXE: &str = "RiotClientServices.exe";
+
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+pub const LOCALES: [(&str, &str); 16] = [
+    ("en_US", "English (US)"),
+    ("en_GB", "English (UK)"),
+    ("pl_PL", "Polish"),
+    ("de_DE", "German"),
+    ("es_ES", "Spanish (Spain)"),
+    ("es_MX", "Spanish (Latin America)"),
+    ("fr_FR", "French"),
+    ("it_IT", "Italian"),
+    ("cs_CZ", "Czech"),
+    ("el_GR", "Greek"),
+    ("hu_HU", "Hungarian"),
+    ("ro_RO", "Romanian"),
+    ("ru_RU", "Russian"),
+    ("tr_TR", "Turkish"),
+    ("pt_BR", "Portuguese (Brazil)"),
+    ("ja_JP", "Japanese"),
+];
+
+const EXPECTED_SIBLINGS: [&str; 5] = [
+    "RiotClientElectron",
+    "RiotClientUx.exe",
+    "RiotClientCrashHandler.exe",
+    "Plugins",
+    "UX",
+];
+
+#[derive(Deserialize, Default)]
+struct RiotClientInstalls {
+    #[serde(default)]
+    rc_default: Option<String>,
+    #[serde(default)]
+    rc_live: Option<String>,
+    #[serde(default)]
+    rc_beta: Option<String>,
+    #[serde(default)]
+    associated_client: HashMap<String, String>,
+}
+
+// The original value of every key `apply_region_and_locale` changed, per file.
+pub struct ClientSettingsBackup {
+    values: Vec<(PathBuf, &'static str, String)>,
+}
+
+// A settings file that could not be written, with the reason.
+pub type FileError = (PathBuf, std::io::Error);
+
+impl ClientSettingsBackup {
+    // Puts the original values back into the files as they are now, so anything the
+    // clients wrote to them in the meantime is kept.
+    pub fn restore(self) -> Vec<FileError> {
+        let mut paths: Vec<&PathBuf> = Vec::new();
+        for (path, _, _) in &self.values {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+
+        let mut failed = Vec::new();
+        for path in paths {
+            let result = fs::read_to_string(path).and_then(|current| {
+                let restored = self
+                    .values
+                    .iter()
+                    .filter(|(value_path, _, _)| value_path == path)
+                    .fold(current.clone(), |content, (_, key, value)| write_yaml_value(&content, key, value));
+                if restored == current { Ok(()) } else { fs::write(path, restored) }
+            });
+            if let Err(e) = result {
+                failed.push((path.clone(), e));
+            }
+        }
+        failed
+    }
+}
+
+/// Running processes first, then Riot's RiotClientInstalls.json, then the usual install folders.
+pub fn detect_install_paths() -> Vec<String> {
+    let mut found: Vec<String> = Vec::new();
+
+    let candidates = paths_from_running_processes()
+        .into_iter()
+        .chain(paths_from_installs_json())
+        .chain(paths_from_common_locations());
+
+    for candidate in candidates {
+        if !candidate.is_file() {
+            continue;
+        }
+        let candidate = normalize(&candidate);
+        if !found.iter().any(|p| p.eq_ignore_ascii_case(&candidate)) {
+            found.push(candidate);
+        }
+    }
+
+    found
+}
+
+// Detection shells out to PowerShell, so it runs on a worker thread instead of during a frame.
+#[derive(Default)]
+pub struct InstallSearch {
+    found: Option<Vec<String>>,
+    pending: Option<mpsc::Receiver<Vec<String>>>,
+}
+
+impl InstallSearch {
+    pub fn restart(&mut self) {
+        *self = Self::default();
+    }
+
+    // The detected paths, or None while the search is still running. Starts one when needed.
+    pub fn poll(&mut self) -> Option<&[String]> {
+        if self.found.is_none() {
+            let receiver = self.pending.get_or_insert_with(|| {
+                let (sender, receiver) = mpsc::channel();
+                thread::spawn(move || {
+                    let _ = sender.send(detect_install_paths());
+                });
+                receiver
+            });
+            match receiver.try_recv() {
+                Ok(paths) => self.found = Some(paths),
+                Err(mpsc::TryRecvError::Disconnected) => self.found = Some(Vec::new()),
+                Err(mpsc::TryRecvError::Empty) => return None,
+            }
+            self.pending = None;
+        }
+        self.found.as_deref()
+    }
+}
+
+// Validation may ask PowerShell for the publisher, so confirming a path checks it on a worker thread.
+#[derive(Default)]
+pub struct InstallCheck {
+    pending: Option<(String, mpsc::Receiver<Result<(), String>>)>,
+}
+
+impl InstallCheck {
+    pub fn start(&mut self, path: String) {
+        let (sender, receiver) = mpsc::channel();
+        let checked = path.clone();
+        thread::spawn(move || {
+            let _ = sender.send(validate_install(&checked));
+        });
+        self.pending = Some((path, receiver));
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    // The checked path and the outcome, once the check has finished.
+    pub fn poll(&mut self) -> Option<(String, Result<(), String>)> {
+        let (_, receiver) = self.pending.as_ref()?;
+        let result = match receiver.try_recv() {
+            Ok(result) => result,
+            Err(mpsc::TryRecvError::Empty) => return None,
+            Err(mpsc::TryRecvError::Disconnected) => Err(t!("install.check_failed").to_owned()),
+        };
+        let (path, _) = self.pending.take()?;
+        Some((path, result))
+    }
+}
+
+fn normalize(path: &Path) -> String {
+    path.to_string_lossy().replace('/', "\\")
+}
+
+fn program_data_dir() -> PathBuf {
+    let program_data = std::env::var("ProgramData").unwrap_or_else(|_| r"C:\ProgramData".to_owned());
+    Path::new(&program_data).join("Riot Games")
+}
+
+fn load_installs_json() -> RiotClientInstalls {
+    match fs::read_to_string(program_data_dir().join("RiotClientInstalls.json")) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_default(),
+        Err(_) => RiotClientInstalls::default(),
+    }
+}
+
+fn paths_from_installs_json() -> Vec<PathBuf> {
+    let installs = load_installs_json();
+
+    [installs.rc_default, installs.rc_live, installs.rc_beta]
+        .into_iter()
+        .flatten()
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn paths_from_running_processes() -> Vec<PathBuf> {
+    running_process_paths("RiotClientServices")
+}
+
+fn running_process_paths(process_name: &str) -> Vec<PathBuf> {
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "Get-Process $env:RUSTY_LEAGUE_PROCESS -ErrorAction SilentlyContinue | Select-Object -ExpandProperty Path",
+        ])
+        .env("RUSTY_LEAGUE_PROCESS", process_name)
+        .creation_flags(CREATE_NO_WINDOW)
+        .output();
+
+    match output {
+        Ok(output) => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(PathBuf::from)
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn paths_from_common_locations() -> Vec<PathBuf> {
+    let mut roots: Vec<PathBuf> = ('C'..='H')
+        .map(|drive| PathBuf::from(format!(r"{}:\Riot Games", drive)))
+        .collect();
+
+    for var in ["ProgramFiles", "ProgramFiles(x86)"] {
+        if let Ok(dir) = std::env::var(var) {
+            roots.push(Path::new(&dir).join("Riot Games"));
+        }
+    }
+
+    roots
+        .into_iter()
+        .map(|root| root.join("Riot Client").join(RIOT_CLIENT_EXE))
+        .collect()
+}
+
+pub fn validate_install(path: &str) -> Result<(), String> {
+    if path.trim().is_empty() {
+        return Err(t!("install.path_empty").to_owned());
+    }
+
+    let path = Path::new(path);
+    if !path.exists() {
+        return Err(t!("install.missing", path = path.display()));
+    }
+    if !path.is_file() {
+        return Err(t!("install.folder", path = path.display(), exe = RIOT_CLIENT_EXE));
+    }
+
+    let file_name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    if !file_name.eq_ignore_ascii_case(RIOT_CLIENT_EXE) {
+        return Err(t!("install.wrong_file", file = file_name, exe = RIOT_CLIENT_EXE));
+    }
+
+    let mut header = [0u8; 2];
+    File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .map_err(|e| t!("install.unreadable", path = path.display(), error = e))?;
+    if &header != b"MZ" {
+        return Err(t!("install.not_executable", path = path.display()));
+    }
+
+    if let Some(company) = file_company_name(path)
+        && !company.contains("Riot Games")
+    {
+        return Err(t!("install.wrong_publisher", path = path.display(), company = company));
+    }
+
+    let has_siblings = path
+        .parent()
+        .map(|dir| EXPECTED_SIBLINGS.iter().any(|name| dir.join(name).exists()))
+        .unwrap_or(false);
+    if !has_siblings {
+        return Err(t!("install.incomplete", exe = RIOT_CLIENT_EXE));
+    }
+
+    Ok(())
+}
+
+type CompanyNameCache = HashMap<PathBuf, (SystemTime, Option<String>)>;
+
+// Cached per path and modification time, because asking PowerShell takes a noticeable moment.
+fn file_company_name(path: &Path) -> Option<String> {
+    static CACHE: OnceLock<Mutex<CompanyNameCache>> = OnceLock::new();
+
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some((cached_at, company)) = cache.lock().ok()?.get(path)
+        && *cached_at == modified
+    {
+        return company.clone();
+    }
+
+    let company = query_company_name(path);
+    cache.lock().ok()?.insert(path.to_path_buf(), (modified, company.clone()));
+    company
+}
+
+fn query_company_name(path: &Path) -> Option<String> {
+    let output = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-Command",
+            "(Get-Item -LiteralPath $env:RUSTY_LEAGUE_EXE).VersionInfo.CompanyName",
+        ])
+        .env("RUSTY_LEAGUE_EXE", path)
+        .creation_flags(CREATE_NO_WINDOW)
+        .output()
+        .ok()?;
+
+    let company = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    if output.status.success() && !company.is_empty() {
+        Some(company)
+    } else {
+        None
+    }
+}
+
+fn league_product_settings_path() -> PathBuf {
+    program_data_dir()
+        .join("Metadata")
+        .join("league_of_legends.live")
+        .join("league_of_legends.live.product_settings.yaml")
+}
+
+pub fn league_install_dirs() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = running_process_paths("LeagueClientUx")
+        .into_iter()
+        .filter_map(|exe| exe.parent().map(Path::to_path_buf))
+        .collect();
+
+    if let Ok(content) = fs::read_to_string(league_product_settings_path())
+        && let Some(value) = read_yaml_value(&content, "product_install_full_path")
+    {
+        dirs.push(PathBuf::from(value));
+    }
+
+    dirs.extend(load_installs_json().associated_client.into_keys().map(PathBuf::from));
+
+    for riot_client in detect_install_paths() {
+        if let Some(riot_games) = Path::new(&riot_client).parent().and_then(Path::parent) {
+            dirs.push(riot_games.join("League of Legends"));
+        }
+    }
+
+    let mut unique: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        if dir.is_dir() && !unique.iter().any(|d| normalize(d).eq_ignore_ascii_case(&normalize(&dir))) {
+            unique.push(dir);
+        }
+    }
+    unique
+}
+
+// Writes region and locale into the League settings files the Riot Client reads on launch.
+// Empty values are left untouched. The returned backup holds the original values of the
+// changed keys, next to the files that could not be updated.
+// Looks for running clients through PowerShell, so call it off the UI thread.
+pub fn apply_region_and_locale(region: &str, locale: &str) -> (ClientSettingsBackup, Vec<FileError>) {
+    // Key paths per file: the product settings only know the locale.
+    let mut files = vec![(league_product_settings_path(), None, Some("settings.locale"))];
+    files.extend(league_install_dirs().into_iter().map(|dir| {
+        (
+            dir.join("Config").join("LeagueClientSettings.yaml"),
+            Some("install.globals.region"),
+            Some("install.globals.locale"),
+        )
+    }));
+
+    let mut backup = ClientSettingsBackup { values: Vec::new() };
+    let mut failed = Vec::new();
+
+    for (path, region_key, locale_key) in files {
+        let Ok(original) = fs::read_to_string(&path) else {
+            continue;
+        };
+
+        let mut updated = original.clone();
+        let mut changed = Vec::new();
+        for (key, value) in [(region_key, region), (locale_key, locale)] {
+            if let Some(key) = key
+                && !value.is_empty()
+                && let Some(previous) = read_yaml_value(&original, key)
+                && previous != value
+            {
+                updated = write_yaml_value(&updated, key, value);
+                changed.push((path.clone(), key, previous));
+            }
+        }
+
+        if updated != original {
+            match fs::write(&path, updated) {
+                Ok(()) => backup.values.extend(changed),
+                Err(e) => failed.push((path, e)),
+            }
+        }
+    }
+
+    (backup, failed)
+}
+
+// Splits a `key: value` line into its indentation, key path and raw value, given the
+// sections opened by the lines before it. Blank lines, comments and list items yield None.
+fn yaml_entry<'a>(line: &'a str, sections: &mut Vec<(usize, &'a str)>) -> Option<(usize, String, &'a str)> {
+    let trimmed = line.trim_start();
+    if trimmed.trim().is_empty() || trimmed.starts_with('#') || trimmed.starts_with('-') {
+        return None;
+    }
+    let indent = line.len() - trimmed.len();
+    let (key, value) = trimmed.split_once(':')?;
+
+    while sections.last().is_some_and(|(section_indent, _)| *section_indent >= indent) {
+        sections.pop();
+    }
+    let path = sections
+        .iter()
+        .map(|(_, section)| *section)
+        .chain(std::iter::once(key))
+        .collect::<Vec<_>>()
+        .join(".");
+    if value.trim().is_empty() {
+        sections.push((indent, key));
+    }
+    Some((indent, path, value))
+}
+
+// `key_path` names nested keys with dots, e.g. `install.globals.locale`; a bare key is top level.
+fn read_yaml_value(content: &str, key_path: &str) -> Option<String> {
+    let mut sections = Vec::new();
+    content.lines().find_map(|line| {
+        let (_, path, value) = yaml_entry(line, &mut sections)?;
+        (path == key_path).then(|| value.trim().trim_matches('"').to_owned())
+    })
+}
+
+// Replaces the value at `key_path` in place, keeping indentation and the rest of the file.
+// Keys with the same name in other sections are left alone.
+fn write_yaml_value(content: &str, key_path: &str, value: &str) -> String {
+    let key = key_path.rsplit('.').next().unwrap_or(key_path);
+    let mut sections = Vec::new();
+    let mut result = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let entry = yaml_entry(line, &mut sections);
+        if let Some((indent, _, _)) = entry.filter(|(_, path, _)| path == key_path) {
+            let indent = &line[..indent];
+            let ending = if line.ends_with("\r\n") {
+                "\r\n"
+            } else if line.ends_with('\n') {
+                "\n"
+            } else {
+                ""
+            };
+            result.push_str(&format!("{}{}: \"{}\"{}", indent, key, value, ending));
+        } else {
+            result.push_str(line);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempDir;
+
+    const CLIENT_SETTINGS: &str = "install:\r\n    globals:\r\n        locale: \"en_US\"\r\n        region: \"EUW\"\r\n    patchline:\r\n        region: \"NA\"\r\nregion: \"EUW\"\r\n";
+
+    #[test]
+    fn writes_only_the_key_at_its_path() {
+        let updated = write_yaml_value(CLIENT_SETTINGS, "install.globals.region", "EUNE");
+        assert_eq!(
+            updated,
+            "install:\r\n    globals:\r\n        locale: \"en_US\"\r\n        region: \"EUNE\"\r\n    patchline:\r\n        region: \"NA\"\r\nregion: \"EUW\"\r\n",
+        );
+    }
+
+    #[test]
+    fn bare_keys_are_top_level() {
+        let updated = write_yaml_value(CLIENT_SETTINGS, "region", "EUNE");
+        assert!(updated.ends_with("region: \"EUNE\"\r\n"));
+        assert_eq!(read_yaml_value(&updated, "install.globals.region").as_deref(), Some("EUW"));
+        assert_eq!(read_yaml_value(&updated, "install.patchline.region").as_deref(), Some("NA"));
+    }
+
+    #[test]
+    fn restore_keeps_changes_made_after_the_backup() {
+        let dir = TempDir::new("client-settings");
+        let path = dir.path().join("LeagueClientSettings.yaml");
+        fs::write(&path, write_yaml_value(CLIENT_SETTINGS, "install.globals.locale", "pl_PL")).unwrap();
+        let backup = ClientSettingsBackup {
+            values: vec![(path.clone(), "install.globals.locale", "en_US".to_owned())],
+        };
+
+        // The client rewrites the region while it runs.
+        let during_session = fs::read_to_string(&path).unwrap();
+        fs::write(&path, write_yaml_value(&during_session, "install.globals.region", "EUNE")).unwrap();
+
+        assert!(backup.restore().is_empty());
+        let restored = fs::read_to_string(&path).unwrap();
+        assert_eq!(read_yaml_value(&restored, "install.globals.locale").as_deref(), Some("en_US"));
+        assert_eq!(read_yaml_value(&restored, "install.globals.region").as_deref(), Some("EUNE"));
+        assert_eq!(read_yaml_value(&restored, "install.patchline.region").as_deref(), Some("NA"));
+    }
+
+    #[test]
+    fn restore_reports_files_that_are_gone() {
+        let dir = TempDir::new("client-settings");
+        let path = dir.path().join("missing.yaml");
+        let backup = ClientSettingsBackup {
+            values: vec![(path.clone(), "settings.locale", "en_US".to_owned())],
+        };
+        let failed = backup.restore();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].0, path);
+    }
+
+    #[test]
+    fn reads_values_with_colons() {
+        let content = "product_install_full_path: \"C:/Riot Games/League of Legends\"\nsettings:\n  locale: pl_PL\n";
+        assert_eq!(
+            read_yaml_value(content, "product_install_full_path").as_deref(),
+            Some("C:/Riot Games/League of Legends"),
+        );
+        assert_eq!(read_yaml_value(content, "settings.locale").as_deref(), Some("pl_PL"));
+        assert_eq!(read_yaml_value(content, "locale"), None);
+    }
+}
@@ -1,6 +1,181 @@
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::time::Duration;
 use directories::ProjectDirs;
+use crate::i18n::{Language, t};
+use crate::presets::LoadoutPreset;
+use crate::theme::{self, ThemeMode};
+
+const MAX_TIMING_SAMPLES: usize = 10;
+
+// Pixels per point offered in Settings, next to following the monitor's scale factor.
+pub const UI_SCALES: [f32; 6] = [0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+
+// Logical pixels, as winit takes them when the viewport is created, with the scale
+// factor of the monitor the window was on to turn them back into physical ones.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct WindowGeometry {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    #[serde(default)]
+    pub maximized: bool,
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f32,
+}
+
+impl WindowGeometry {
+    // Left, top, right and bottom in physical virtual-screen pixels, as Windows lays out monitors.
+    pub fn physical_bounds(&self) -> [i32; 4] {
+        let scale = self.scale_factor;
+        [
+            (self.x * scale).round() as i32,
+            (self.y * scale).round() as i32,
+            ((self.x + self.width) * scale).round() as i32,
+            ((self.y + self.height) * scale).round() as i32,
+        ]
+    }
+}
+
+fn default_scale_factor() -> f32 {
+    1.0
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TimingProfile {
+    Fast,
+    #[default]
+    Normal,
+    Slow,
+    Adaptive,
+}
+
+impl TimingProfile {
+    pub const ALL: [TimingProfile; 4] = [
+        TimingProfile::Fast,
+        TimingProfile::Normal,
+        TimingProfile::Slow,
+        TimingProfile::Adaptive,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimingProfile::Fast => t!("timing.fast"),
+            TimingProfile::Normal => t!("timing.normal"),
+            TimingProfile::Slow => t!("timing.slow"),
+            TimingProfile::Adaptive => t!("timing.adaptive"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AccountSort {
+    #[default]
+    Manual,
+    Name,
+    Region,
+    LastUsed,
+    MostUsed,
+    Rank,
+}
+
+impl AccountSort {
+    pub const ALL: [AccountSort; 6] = [
+        AccountSort::Manual,
+        AccountSort::Name,
+        AccountSort::Region,
+        AccountSort::LastUsed,
+        AccountSort::MostUsed,
+        AccountSort::Rank,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AccountSort::Manual => t!("sort.manual"),
+            AccountSort::Name => t!("sort.name"),
+            AccountSort::Region => t!("sort.region"),
+            AccountSort::LastUsed => t!("sort.last_used"),
+            AccountSort::MostUsed => t!("sort.most_used"),
+            AccountSort::Rank => t!("sort.rank"),
+        }
+    }
+
+    pub fn next(&self) -> AccountSort {
+        let pos = Self::ALL.iter().position(|sort| sort == self).unwrap_or(0);
+        Self::ALL[(pos + 1) % Self::ALL.len()]
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LoginTiming {
+    pub login_screen_timeout: Duration,
+    pub poll_interval: Duration,
+    pub heavy_search_interval: Duration,
+    pub focus_delay: Duration,
+    pub fallback_delay: Duration,
+    pub keystroke_delay: Duration,
+    pub league_client_timeout: Duration,
+}
+
+impl LoginTiming {
+    pub fn fast() -> Self {
+        Self {
+            login_screen_timeout: Duration::from_secs(30),
+            poll_interval: Duration::from_millis(50),
+            heavy_search_interval: Duration::from_millis(1000),
+            focus_delay: Duration::from_millis(250),
+            fallback_delay: Duration::from_secs(3),
+            keystroke_delay: Duration::from_millis(50),
+            league_client_timeout: Duration::from_secs(120),
+        }
+    }
+
+    pub fn normal() -> Self {
+        Self {
+            login_screen_timeout: Duration::from_secs(60),
+            poll_interval: Duration::from_millis(50),
+            heavy_search_interval: Duration::from_millis(1000),
+            focus_delay: Duration::from_millis(500),
+            fallback_delay: Duration::from_secs(5),
+            keystroke_delay: Duration::from_millis(100),
+            league_client_timeout: Duration::from_secs(180),
+        }
+    }
+
+    pub fn slow() -> Self {
+        Self {
+            login_screen_timeout: Duration::from_secs(180),
+            poll_interval: Duration::from_millis(100),
+            heavy_search_interval: Duration::from_millis(2000),
+            focus_delay: Duration::from_millis(1500),
+            fallback_delay: Duration::from_secs(10),
+            keystroke_delay: Duration::from_millis(250),
+            league_client_timeout: Duration::from_secs(300),
+        }
+    }
+
+    // Scales the Normal profile by how long this machine usually takes to show the login screen.
+    // The timeout never drops below Normal's, and doubles the longest recent wait so a
+    // launch that timed out gets more time on the next try.
+    fn adaptive(typical: Duration, longest: Duration) -> Self {
+        let normal = Self::normal();
+        let factor = (typical.as_secs_f32() / 15.0).clamp(0.5, 3.0);
+        let timeout = (typical * 3)
+            .max(longest * 2)
+            .clamp(normal.login_screen_timeout, Duration::from_secs(300));
+
+        Self {
+            login_screen_timeout: timeout,
+            poll_interval: normal.poll_interval,
+            heavy_search_interval: normal.heavy_search_interval.mul_f32(factor.max(1.0)),
+            focus_delay: normal.focus_delay.mul_f32(factor),
+            fallback_delay: normal.fallback_delay.mul_f32(factor),
+            keystroke_delay: normal.keystroke_delay.mul_f32(factor),
+            league_client_timeout: normal.league_client_timeout.mul_f32(factor.max(1.0)),
+        }
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Settings {
@@ -9,6 +184,64 @@ pub struct Settings {
     pub minimalist_mode: bool,
     #[serde(default)]
     pub start_with_windows: bool,
+    #[serde(default)]
+    pub timing_profile: TimingProfile,
+    #[serde(default)]
+    pub login_screen_samples_ms: Vec<u64>,
+    #[serde(default)]
+    pub restore_client_locale: bool,
+    #[serde(default)]
+    pub auto_launch_league: bool,
+    #[serde(default)]
+    pub decay_notifications: bool,
+    #[serde(default = "default_decay_warning_days")]
+    pub decay_warning_days: u32,
+    #[serde(default = "default_auto_accept_delay_secs")]
+    pub auto_accept_delay_secs: f32,
+    // Shared by every saved account.
+    #[serde(default)]
+    pub presets: Vec<LoadoutPreset>,
+    #[serde(default)]
+    pub picker_grouped: bool,
+    #[serde(default)]
+    pub picker_group_filter: Option<String>,
+    #[serde(default)]
+    pub collapsed_groups: Vec<String>,
+    // Display order only; `saved_accounts` always keeps the manual order.
+    #[serde(default)]
+    pub account_sort: AccountSort,
+    #[serde(default = "default_show_recent_accounts")]
+    pub show_recent_accounts: bool,
+    // Keeps the client watcher running just to save every finished game.
+    #[serde(default)]
+    pub record_every_game: bool,
+    #[serde(default)]
+    pub ui_language: Language,
+    #[serde(default)]
+    pub theme_mode: ThemeMode,
+    #[serde(default = "default_accent_color")]
+    pub accent_color: [u8; 3],
+    // None follows the scale factor the OS reports for the monitor.
+    #[serde(default)]
+    pub ui_scale: Option<f32>,
+    #[serde(default)]
+    pub window: Option<WindowGeometry>,
+}
+
+fn default_accent_color() -> [u8; 3] {
+    theme::DEFAULT_ACCENT
+}
+
+fn default_show_recent_accounts() -> bool {
+    true
+}
+
+fn default_decay_warning_days() -> u32 {
+    3
+}
+
+fn default_auto_accept_delay_secs() -> f32 {
+    2.0
 }
 
 impl Default for Settings {
@@ -17,6 +250,61 @@ impl Default for Settings {
             riot_client_path: String::new(),
             minimalist_mode: false,
             start_with_windows: false,
+            timing_profile: TimingProfile::Normal,
+            login_screen_samples_ms: Vec::new(),
+            restore_client_locale: false,
+            auto_launch_league: false,
+            decay_notifications: false,
+            decay_warning_days: default_decay_warning_days(),
+            auto_accept_delay_secs: default_auto_accept_delay_secs(),
+            presets: Vec::new(),
+            picker_grouped: false,
+            picker_group_filter: None,
+            collapsed_groups: Vec::new(),
+            account_sort: AccountSort::Manual,
+            show_recent_accounts: default_show_recent_accounts(),
+            record_every_game: false,
+            ui_language: Language::default(),
+            theme_mode: ThemeMode::default(),
+            accent_color: default_accent_color(),
+            ui_scale: None,
+            window: None,
+        }
+    }
+}
+
+impl Settings {
+    pub fn login_timing(&self) -> LoginTiming {
+        match self.timing_profile {
+            TimingProfile::Fast => LoginTiming::fast(),
+            TimingProfile::Normal => LoginTiming::normal(),
+            TimingProfile::Slow => LoginTiming::slow(),
+            TimingProfile::Adaptive => match (self.typical_login_screen_time(), self.longest_login_screen_time()) {
+                (Some(typical), Some(longest)) => LoginTiming::adaptive(typical, longest),
+                _ => LoginTiming::normal(),
+            },
+        }
+    }
+
+    pub fn typical_login_screen_time(&self) -> Option<Duration> {
+        if self.login_screen_samples_ms.is_empty() {
+            return None;
+        }
+        let mut samples = self.login_screen_samples_ms.clone();
+        samples.sort_unstable();
+        Some(Duration::from_millis(samples[samples.len() / 2]))
+    }
+
+    fn longest_login_screen_time(&self) -> Option<Duration> {
+        self.login_screen_samples_ms.iter().max().map(|&ms| Duration::from_millis(ms))
+    }
+
+    // A launch that timed out is recorded with the time it waited, which is a lower bound.
+    pub fn record_login_screen_time(&mut self, elapsed: Duration) {
+        self.login_screen_samples_ms.push(elapsed.as_millis() as u64);
+        if self.login_screen_samples_ms.len() > MAX_TIMING_SAMPLES {
+            let excess = self.login_screen_samples_ms.len() - MAX_TIMING_SAMPLES;
+            self.login_screen_samples_ms.drain(..excess);
         }
     }
 }
@@ -37,6 +325,14 @@ pub fn save_settings(settings: &Settings) -> std::io::Result<()> {
     }
 }
 
+// Stores the learned timing samples without touching anything else in the file, so edits
+// still open in the Settings view are not saved along with them.
+pub fn save_login_screen_samples(samples: &[u64]) -> std::io::Result<()> {
+    let mut stored = load_settings();
+    stored.login_screen_samples_ms = samples.to_vec();
+    save_settings(&stored)
+}
+
 pub fn load_settings() -> Settings {
     if let Some(proj_dirs) = ProjectDirs::from("pl", "Rusty Credentials", "") {
         let file_path = proj_dirs.config_dir().join("settings.json");
@@ -99,3 +395,54 @@ pub fn set_autostart(enable: bool) -> Result<(), String> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adaptive_with(samples_ms: &[u64]) -> LoginTiming {
+        let settings = Settings {
+            timing_profile: TimingProfile::Adaptive,
+            login_screen_samples_ms: samples_ms.to_vec(),
+            ..Settings::default()
+        };
+        settings.login_timing()
+    }
+
+    #[test]
+    fn adaptive_timeout_never_drops_below_normal() {
+        let timing = adaptive_with(&[2_000]);
+        assert_eq!(timing.login_screen_timeout, LoginTiming::normal().login_screen_timeout);
+    }
+
+    #[test]
+    fn adaptive_timeout_widens_after_a_timeout() {
+        let timing = adaptive_with(&[5_000, 6_000, 5_500, 90_000]);
+        assert_eq!(timing.login_screen_timeout, Duration::from_secs(180));
+    }
+
+    #[test]
+    fn window_bounds_follow_the_monitor_scale() {
+        let window = WindowGeometry {
+            x: 1_280.0,
+            y: 100.0,
+            width: 800.0,
+            height: 600.0,
+            maximized: false,
+            scale_factor: 1.5,
+        };
+        assert_eq!(window.physical_bounds(), [1_920, 150, 3_120, 1_050]);
+
+        let saved: WindowGeometry = serde_json::from_str(r#"{"x":10.0,"y":20.0,"width":30.0,"height":40.0}"#).unwrap();
+        assert_eq!(saved.physical_bounds(), [10, 20, 40, 60]);
+    }
+
+    #[test]
+    fn keeps_only_the_latest_samples() {
+        let mut settings = Settings::default();
+        for ms in 0..15 {
+            settings.record_login_screen_time(Duration::from_millis(ms));
+        }
+        assert_eq!(settings.login_screen_samples_ms, (5..15).collect::<Vec<u64>>());
+    }
+}
@@ -0,0 +1,438 @@
+use eframe::egui;
+use crate::i18n::{self, Language, t};
+use crate::settings::{self, AccountSort, Settings, TimingProfile};
+use crate::theme::ThemeMode;
+use crate::widgets;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Section {
+    General,
+    Appearance,
+    RiotClient,
+    Accounts,
+    Notifications,
+}
+
+impl Section {
+    pub const ALL: [Section; 5] = [
+        Section::General,
+        Section::Appearance,
+        Section::RiotClient,
+        Section::Accounts,
+        Section::Notifications,
+    ];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Section::General => "settings.section.general",
+            Section::Appearance => "settings.section.appearance",
+            Section::RiotClient => "settings.section.riot_client",
+            Section::Accounts => "settings.section.accounts",
+            Section::Notifications => "settings.section.notifications",
+        }
+    }
+}
+
+// Settings whose editor is too specific for a generic widget; the app draws them itself.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Custom {
+    RiotClientPath,
+}
+
+// Draws the custom settings. They may keep their value outside `Settings` until the app
+// accepts it, so the editor also decides what their default is.
+pub trait CustomEditor {
+    // Returns true when `settings` changed.
+    fn show(&mut self, ui: &mut egui::Ui, settings: &mut Settings, custom: Custom) -> bool;
+    fn is_default(&self, settings: &Settings, custom: Custom) -> bool;
+    fn reset(&mut self, settings: &mut Settings, custom: Custom);
+}
+
+pub enum Widget {
+    Toggle(fn(&mut Settings) -> &mut bool),
+    Choice {
+        options: fn() -> Vec<String>,
+        get: fn(&Settings) -> usize,
+        set: fn(&mut Settings, usize),
+    },
+    Decimal { value: fn(&mut Settings) -> &mut f32, range: (f32, f32), unit: &'static str },
+    Integer { value: fn(&mut Settings) -> &mut u32, range: (u32, u32), unit: &'static str },
+    Color(fn(&mut Settings) -> &mut [u8; 3]),
+    Custom(Custom),
+}
+
+impl Widget {
+    fn is_default(&self, settings: &mut Settings, defaults: &mut Settings, editor: &dyn CustomEditor) -> bool {
+        match self {
+            Widget::Toggle(value) => *value(settings) == *value(defaults),
+            Widget::Choice { get, .. } => get(settings) == get(defaults),
+            Widget::Decimal { value, .. } => *value(settings) == *value(defaults),
+            Widget::Integer { value, .. } => *value(settings) == *value(defaults),
+            Widget::Color(value) => *value(settings) == *value(defaults),
+            Widget::Custom(custom) => editor.is_default(settings, *custom),
+        }
+    }
+
+    fn reset(&self, settings: &mut Settings, defaults: &mut Settings, editor: &mut dyn CustomEditor) {
+        match self {
+            Widget::Toggle(value) => *value(settings) = *value(defaults),
+            Widget::Choice { get, set, .. } => set(settings, get(defaults)),
+            Widget::Decimal { value, .. } => *value(settings) = *value(defaults),
+            Widget::Integer { value, .. } => *value(settings) = *value(defaults),
+            Widget::Color(value) => *value(settings) = *value(defaults),
+            Widget::Custom(custom) => editor.reset(settings, *custom),
+        }
+    }
+}
+
+// What has to happen besides saving once a setting changes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Effect {
+    None,
+    Language,
+    Theme,
+    Autostart,
+    WatcherConfig,
+    DecayCheck,
+}
+
+// Label and description are catalog keys, so they follow the app language.
+pub struct SettingDef {
+    pub section: Section,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub widget: Widget,
+    pub effect: Effect,
+    pub visible: fn(&Settings) -> bool,
+    // Extra text that depends on the current values, shown under the description.
+    pub hint: Option<fn(&Settings) -> String>,
+}
+
+impl SettingDef {
+    const fn new(section: Section, label: &'static str, description: &'static str, widget: Widget) -> Self {
+        Self { section, label, description, widget, effect: Effect::None, visible: |_| true, hint: None }
+    }
+
+    const fn effect(mut self, effect: Effect) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    const fn visible_when(mut self, visible: fn(&Settings) -> bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    const fn hint(mut self, hint: fn(&Settings) -> String) -> Self {
+        self.hint = Some(hint);
+        self
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        query.is_empty()
+            || i18n::tr(self.label).to_lowercase().contains(query)
+            || i18n::tr(self.description).to_lowercase().contains(query)
+    }
+}
+
+macro_rules! choice {
+    ($field:ident, $ty:ident, $label:ident) => {
+        Widget::Choice {
+            options: || $ty::ALL.iter().map(|value| value.$label().to_owned()).collect(),
+            get: |s| $ty::ALL.iter().position(|value| *value == s.$field).unwrap_or(0),
+            set: |s, index| s.$field = $ty::ALL[index],
+        }
+    };
+}
+
+pub const SETTINGS: &[SettingDef] = &[
+    SettingDef::new(Section::General, "settings.app_language", "settings.app_language_desc", choice!(ui_language, Language, native_name))
+        .effect(Effect::Language),
+    SettingDef::new(Section::General, "settings.autostart", "settings.autostart_desc", Widget::Toggle(|s| &mut s.start_with_windows))
+        .effect(Effect::Autostart),
+    SettingDef::new(Section::General, "settings.minimalist", "settings.minimalist_desc", Widget::Toggle(|s| &mut s.minimalist_mode)),
+    SettingDef::new(Section::Appearance, "settings.theme", "settings.theme_desc", choice!(theme_mode, ThemeMode, label))
+        .effect(Effect::Theme),
+    SettingDef::new(Section::Appearance, "settings.accent", "settings.accent_desc", Widget::Color(|s| &mut s.accent_color))
+        .effect(Effect::Theme),
+    SettingDef::new(
+        Section::Appearance,
+        "settings.ui_scale",
+        "settings.ui_scale_desc",
+        Widget::Choice {
+            options: || {
+                std::iter::once(t!("settings.ui_scale_auto").to_owned())
+                    .chain(settings::UI_SCALES.iter().map(|scale| format!("{:.0}%", scale * 100.0)))
+                    .collect()
+            },
+            get: |s| {
+                s.ui_scale
+                    .and_then(|scale| settings::UI_SCALES.iter().position(|known| *known == scale))
+                    .map_or(0, |index| index + 1)
+            },
+            set: |s, index| s.ui_scale = index.checked_sub(1).map(|index| settings::UI_SCALES[index]),
+        },
+    ),
+    SettingDef::new(Section::RiotClient, "settings.riot_path", "settings.riot_path_desc", Widget::Custom(Custom::RiotClientPath)),
+    SettingDef::new(Section::RiotClient, "settings.login_timing", "settings.login_timing_desc", choice!(timing_profile, TimingProfile, label))
+        .hint(timing_hint),
+    SettingDef::new(Section::RiotClient, "settings.restore_locale", "settings.restore_locale_desc", Widget::Toggle(|s| &mut s.restore_client_locale)),
+    SettingDef::new(Section::RiotClient, "settings.auto_launch", "settings.auto_launch_desc", Widget::Toggle(|s| &mut s.auto_launch_league)),
+    SettingDef::new(
+        Section::RiotClient,
+        "settings.accept_delay",
+        "settings.accept_delay_desc",
+        Widget::Decimal { value: |s| &mut s.auto_accept_delay_secs, range: (0.0, 10.0), unit: "unit.seconds" },
+    )
+    .effect(Effect::WatcherConfig),
+    SettingDef::new(Section::Accounts, "settings.recent_first", "settings.recent_first_desc", Widget::Toggle(|s| &mut s.show_recent_accounts)),
+    SettingDef::new(Section::Accounts, "settings.account_order", "settings.account_order_desc", choice!(account_sort, AccountSort, label)),
+    SettingDef::new(Section::Accounts, "settings.record_every_game", "settings.record_every_game_desc", Widget::Toggle(|s| &mut s.record_every_game))
+        .effect(Effect::WatcherConfig),
+    SettingDef::new(Section::Notifications, "settings.decay_notifications", "settings.decay_notifications_desc", Widget::Toggle(|s| &mut s.decay_notifications))
+        .effect(Effect::DecayCheck),
+    SettingDef::new(
+        Section::Notifications,
+        "settings.decay_warning_days",
+        "settings.decay_warning_days_desc",
+        Widget::Integer { value: |s| &mut s.decay_warning_days, range: (0, 28), unit: "unit.days" },
+    )
+    .effect(Effect::DecayCheck)
+    .visible_when(|s| s.decay_notifications),
+];
+
+fn timing_hint(settings: &Settings) -> String {
+    match settings.timing_profile {
+        TimingProfile::Adaptive => match settings.typical_login_screen_time() {
+            Some(typical) => t!(
+                "settings.timing_learned",
+                seconds = format!("{:.1}", typical.as_secs_f32()),
+                launches = settings.login_screen_samples_ms.len(),
+            ),
+            None => t!("settings.timing_unlearned").to_owned(),
+        },
+        _ => {
+            let timing = settings.login_timing();
+            t!(
+                "settings.timing_fixed",
+                seconds = timing.login_screen_timeout.as_secs(),
+                millis = timing.keystroke_delay.as_millis(),
+            )
+        }
+    }
+}
+
+// A setting that changed this frame. Dragging a value or typing it in changes it on every
+// frame; `finished` is set once the user lets go, which is when it is worth saving.
+pub struct Edit {
+    pub def: &'static SettingDef,
+    pub finished: bool,
+}
+
+// Draws every visible setting that matches `search`, grouped by section, and returns
+// the ones that changed this frame.
+pub fn show(ui: &mut egui::Ui, settings: &mut Settings, search: &str, editor: &mut dyn CustomEditor) -> Vec<Edit> {
+    let query = search.trim().to_lowercase();
+    let mut defaults = Settings::default();
+    let mut edits = Vec::new();
+
+    for section in Section::ALL {
+        let defs: Vec<&'static SettingDef> = SETTINGS
+            .iter()
+            .filter(|def| def.section == section && (def.visible)(settings) && def.matches(&query))
+            .collect();
+        if defs.is_empty() {
+            continue;
+        }
+
+        ui.add_space(10.0);
+        ui.label(egui::RichText::new(i18n::tr(section.label_key())).strong().size(16.0));
+        ui.separator();
+
+        for def in defs {
+            if let Some(finished) = setting_row(ui, def, settings, &mut defaults, editor) {
+                edits.push(Edit { def, finished });
+            }
+            ui.add_space(8.0);
+        }
+    }
+
+    edits
+}
+
+// Some(finished) when the setting changed.
+fn setting_row(
+    ui: &mut egui::Ui,
+    def: &SettingDef,
+    settings: &mut Settings,
+    defaults: &mut Settings,
+    editor: &mut dyn CustomEditor,
+) -> Option<bool> {
+    let mut edit = None;
+    ui.horizontal(|ui| {
+        ui.label(i18n::tr(def.label));
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            let is_default = def.widget.is_default(settings, defaults, editor);
+            let reset = ui.add_enabled(!is_default, egui::Button::new("⟲").small())
+                .on_hover_text(t!("settings.reset"));
+            if reset.clicked() {
+                def.widget.reset(settings, defaults, editor);
+                edit = Some(true);
+            }
+            if let Some(finished) = setting_widget(ui, def, settings) {
+                edit = Some(finished && edit.unwrap_or(true));
+            }
+        });
+    });
+
+    if let Widget::Custom(custom) = def.widget
+        && editor.show(ui, settings, custom)
+    {
+        edit = Some(true);
+    }
+    ui.label(egui::RichText::new(i18n::tr(def.description)).small().weak());
+    if let Some(hint) = def.hint {
+        ui.label(egui::RichText::new(hint(settings)).small().weak());
+    }
+    edit
+}
+
+// Laid out right to left, next to the reset button. Some(finished) when the value changed.
+fn setting_widget(ui: &mut egui::Ui, def: &SettingDef, settings: &mut Settings) -> Option<bool> {
+    match &def.widget {
+        Widget::Toggle(value) => ui.add(widgets::toggle_switch(value(settings))).changed().then_some(true),
+        Widget::Choice { options, get, set } => {
+            let options = options();
+            let mut selected = get(settings);
+            egui::ComboBox::from_id_salt(def.label)
+                .selected_text(options.get(selected).cloned().unwrap_or_default())
+                .width(140.0)
+                .show_ui(ui, |ui| {
+                    for (index, option) in options.iter().enumerate() {
+                        ui.selectable_value(&mut selected, index, option);
+                    }
+                });
+            if selected != get(settings) {
+                set(settings, selected);
+                Some(true)
+            } else {
+                None
+            }
+        }
+        Widget::Decimal { value, range, unit } => drag_edit(ui.add(
+            egui::DragValue::new(value(settings))
+                .range(range.0..=range.1)
+                .speed(0.1)
+                .suffix(format!(" {}", i18n::tr(unit))),
+        )),
+        Widget::Integer { value, range, unit } => drag_edit(ui.add(
+            egui::DragValue::new(value(settings))
+                .range(range.0..=range.1)
+                .suffix(format!(" {}", i18n::tr(unit))),
+        )),
+        // The picker keeps changing the color while the pointer is held down in it.
+        Widget::Color(value) => ui
+            .color_edit_button_srgb(value(settings))
+            .changed()
+            .then(|| !ui.input(|i| i.pointer.any_down())),
+        Widget::Custom(_) => None,
+    }
+}
+
+fn drag_edit(response: egui::Response) -> Option<bool> {
+    let finished = response.drag_stopped() || response.lost_focus();
+    if response.changed() {
+        Some(finished || !(response.dragged() || response.has_focus()))
+    } else {
+        // Letting go without moving any further still ends the edit.
+        finished.then_some(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Stands in for the app, which keeps the Riot Client path as a draft.
+    struct DraftEditor(String);
+
+    impl CustomEditor for DraftEditor {
+        fn show(&mut self, _ui: &mut egui::Ui, _settings: &mut Settings, _custom: Custom) -> bool {
+            false
+        }
+
+        fn is_default(&self, _settings: &Settings, _custom: Custom) -> bool {
+            self.0.is_empty()
+        }
+
+        fn reset(&mut self, _settings: &mut Settings, _custom: Custom) {
+            self.0.clear();
+        }
+    }
+
+    fn change(widget: &Widget, settings: &mut Settings, editor: &mut DraftEditor) {
+        match widget {
+            Widget::Toggle(value) => *value(settings) = !*value(settings),
+            Widget::Choice { options, get, set } => set(settings, (get(settings) + 1) % options().len()),
+            Widget::Decimal { value, .. } => *value(settings) += 1.0,
+            Widget::Integer { value, .. } => *value(settings) += 1,
+            Widget::Color(value) => value(settings)[0] ^= 0xff,
+            Widget::Custom(_) => editor.0 = "C:\\Riot Games\\Riot Client\\RiotClientServices.exe".to_owned(),
+        }
+    }
+
+    #[test]
+    fn every_setting_starts_at_its_default_and_resets_back_to_it() {
+        for def in SETTINGS {
+            let mut settings = Settings::default();
+            let mut defaults = Settings::default();
+            let mut editor = DraftEditor(String::new());
+            assert!(def.widget.is_default(&mut settings, &mut defaults, &editor), "{}", def.label);
+
+            change(&def.widget, &mut settings, &mut editor);
+            assert!(!def.widget.is_default(&mut settings, &mut defaults, &editor), "{}", def.label);
+
+            def.widget.reset(&mut settings, &mut defaults, &mut editor);
+            assert!(def.widget.is_default(&mut settings, &mut defaults, &editor), "{}", def.label);
+        }
+    }
+
+    #[test]
+    fn resetting_one_setting_leaves_the_others_alone() {
+        let def = SETTINGS.iter().find(|def| def.label == "settings.minimalist").unwrap();
+        let mut settings = Settings { minimalist_mode: true, show_recent_accounts: false, ..Settings::default() };
+        def.widget.reset(&mut settings, &mut Settings::default(), &mut DraftEditor(String::new()));
+        assert!(!settings.minimalist_mode);
+        assert!(!settings.show_recent_accounts);
+    }
+
+    #[test]
+    fn ui_scale_choice_round_trips_every_option() {
+        let def = SETTINGS.iter().find(|def| def.label == "settings.ui_scale").unwrap();
+        let Widget::Choice { options, get, set } = &def.widget else {
+            panic!("the UI scale is a choice");
+        };
+        assert_eq!(options().len(), settings::UI_SCALES.len() + 1);
+
+        let mut settings = Settings::default();
+        set(&mut settings, 0);
+        assert_eq!(settings.ui_scale, None);
+        assert_eq!(get(&settings), 0);
+
+        for (index, scale) in settings::UI_SCALES.iter().enumerate() {
+            set(&mut settings, index + 1);
+            assert_eq!(settings.ui_scale, Some(*scale));
+            assert_eq!(get(&settings), index + 1);
+        }
+    }
+
+    #[test]
+    fn unknown_ui_scale_shows_as_automatic() {
+        let def = SETTINGS.iter().find(|def| def.label == "settings.ui_scale").unwrap();
+        let Widget::Choice { get, .. } = &def.widget else {
+            panic!("the UI scale is a choice");
+        };
+        let settings = Settings { ui_scale: Some(1.1), ..Settings::default() };
+        assert_eq!(get(&settings), 0);
+    }
+}
@@ -0,0 +1,80 @@
+use eframe::egui::{self, Color32};
+use serde::{Deserialize, Serialize};
+use crate::i18n::t;
+
+pub const DEFAULT_ACCENT: [u8; 3] = [0, 180, 80];
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ThemeMode {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::System, ThemeMode::Light, ThemeMode::Dark];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeMode::System => t!("theme.system"),
+            ThemeMode::Light => t!("theme.light"),
+            ThemeMode::Dark => t!("theme.dark"),
+        }
+    }
+}
+
+// Both the light and the dark style get the accent, so following the system can switch
+// between them without applying the theme again.
+pub fn apply(ctx: &egui::Context, mode: ThemeMode, accent: [u8; 3]) {
+    ctx.set_theme(match mode {
+        ThemeMode::System => egui::ThemePreference::System,
+        ThemeMode::Light => egui::ThemePreference::Light,
+        ThemeMode::Dark => egui::ThemePreference::Dark,
+    });
+
+    let [r, g, b] = accent;
+    let accent = Color32::from_rgb(r, g, b);
+    for theme in [egui::Theme::Dark, egui::Theme::Light] {
+        ctx.style_mut_of(theme, |style| {
+            let visuals = &mut style.visuals;
+            visuals.selection.bg_fill = accent;
+            visuals.selection.stroke.color = readable_on(accent);
+            visuals.hyperlink_color = accent;
+            visuals.text_cursor.stroke.color = accent;
+        });
+    }
+}
+
+// Black or white, whichever stays legible on top of `fill`.
+fn readable_on(fill: Color32) -> Color32 {
+    if fill.intensity() > 0.6 { Color32::BLACK } else { Color32::WHITE }
+}
+
+// Colors for everything the app paints itself, derived from the active egui visuals.
+pub struct Palette {
+    pub accent: Color32,
+    pub danger: Color32,
+    pub warning: Color32,
+    pub success: Color32,
+    pub on_status: Color32,
+    pub switch_off: Color32,
+    pub knob: Color32,
+    pub shadow: Color32,
+}
+
+impl Palette {
+    pub fn of(visuals: &egui::Visuals) -> Self {
+        let dark = visuals.dark_mode;
+        Self {
+            accent: visuals.selection.bg_fill,
+            danger: if dark { Color32::from_rgb(200, 50, 50) } else { Color32::from_rgb(190, 40, 40) },
+            warning: if dark { Color32::from_rgb(230, 150, 40) } else { Color32::from_rgb(205, 120, 20) },
+            success: if dark { Color32::from_rgb(80, 170, 90) } else { Color32::from_rgb(30, 130, 60) },
+            on_status: Color32::WHITE,
+            switch_off: if dark { Color32::from_gray(60) } else { Color32::from_gray(190) },
+            knob: Color32::WHITE,
+            shadow: if dark { Color32::from_black_alpha(100) } else { Color32::from_black_alpha(50) },
+        }
+    }
+}
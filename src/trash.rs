@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use directories::ProjectDirs;
+use crate::credentials::Account;
+
+// Deleted accounts can be restored for this long before they are purged.
+pub const RETENTION_DAYS: u64 = 30;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrashedAccount {
+    pub account: Account,
+    pub deleted_at: u64,
+}
+
+impl TrashedAccount {
+    pub fn is_expired(&self, now: u64) -> bool {
+        now.saturating_sub(self.deleted_at) >= RETENTION_DAYS * 86_400
+    }
+
+    pub fn days_left(&self, now: u64) -> u64 {
+        (self.deleted_at + RETENTION_DAYS * 86_400).saturating_sub(now).div_ceil(86_400)
+    }
+}
+
+pub fn save_trash(trash: &[TrashedAccount]) -> std::io::Result<()> {
+    let proj_dirs = ProjectDirs::from("pl", "Rusty Credentials", "")
+        .ok_or_else(|| std::io::Error::other("Could not determine config directory"))?;
+    let config_dir = proj_dirs.config_dir();
+    fs::create_dir_all(config_dir)?;
+
+    let json = serde_json::to_string_pretty(trash)?;
+    fs::write(config_dir.join("trash.json"), json)
+}
+
+// Drops accounts past the retention period; true when any were dropped.
+pub fn purge_expired(trash: &mut Vec<TrashedAccount>, now: u64) -> bool {
+    let count = trash.len();
+    trash.retain(|entry| !entry.is_expired(now));
+    trash.len() != count
+}
+
+// Accounts past the retention period are dropped from the file while loading.
+pub fn load_trash(now: u64) -> Vec<TrashedAccount> {
+    let Some(content) = ProjectDirs::from("pl", "Rusty Credentials", "")
+        .and_then(|proj_dirs| fs::read_to_string(proj_dirs.config_dir().join("trash.json")).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut trash: Vec<TrashedAccount> = serde_json::from_str(&content).unwrap_or_default();
+    if purge_expired(&mut trash, now) {
+        let _ = save_trash(&trash);
+    }
+    trash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: u64 = 86_400;
+
+    fn deleted_at(deleted_at: u64) -> TrashedAccount {
+        TrashedAccount { account: Account::default(), deleted_at }
+    }
+
+    #[test]
+    fn counts_started_days_left() {
+        let entry = deleted_at(1_000 * DAY);
+        assert_eq!(entry.days_left(1_000 * DAY), RETENTION_DAYS);
+        assert_eq!(entry.days_left(1_000 * DAY + 1), RETENTION_DAYS);
+        assert_eq!(entry.days_left(1_000 * DAY + DAY), RETENTION_DAYS - 1);
+        assert_eq!(entry.days_left((1_000 + RETENTION_DAYS) * DAY - 1), 1);
+        assert_eq!(entry.days_left((1_000 + RETENTION_DAYS) * DAY), 0);
+    }
+
+    #[test]
+    fn expires_after_the_retention_period() {
+        let entry = deleted_at(1_000 * DAY);
+        assert!(!entry.is_expired((1_000 + RETENTION_DAYS) * DAY - 1));
+        assert!(entry.is_expired((1_000 + RETENTION_DAYS) * DAY));
+        // A clock set back never expires anything.
+        assert!(!entry.is_expired(0));
+    }
+
+    #[test]
+    fn purges_only_expired_entries() {
+        let mut trash = vec![deleted_at(1_000 * DAY), deleted_at(1_020 * DAY)];
+        assert!(!purge_expired(&mut trash, 1_025 * DAY));
+        assert_eq!(trash.len(), 2);
+        assert!(purge_expired(&mut trash, (1_000 + RETENTION_DAYS) * DAY));
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].deleted_at, 1_020 * DAY);
+    }
+}
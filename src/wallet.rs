@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use crate::credentials;
+use crate::lcu::LcuClient;
+
+// A value is None when the endpoint behind it could not be read.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct WalletSnapshot {
+    #[serde(default)]
+    pub blue_essence: Option<u64>,
+    #[serde(default)]
+    pub orange_essence: Option<u64>,
+    #[serde(default)]
+    pub riot_points: Option<u64>,
+    #[serde(default)]
+    pub mythic_essence: Option<u64>,
+    // Chests, capsules and orbs that have not been opened yet.
+    #[serde(default)]
+    pub unopened_loot: Option<u32>,
+    #[serde(default)]
+    pub keys: Option<u32>,
+    #[serde(default)]
+    pub champions_owned: Option<u32>,
+    #[serde(default)]
+    pub honor_level: Option<u32>,
+    pub updated_at: u64,
+}
+
+// Each part is optional: an endpoint that fails leaves its values unknown instead of
+// dropping the whole snapshot. Returns None only when nothing could be read.
+pub fn from_client(client: &LcuClient) -> Option<WalletSnapshot> {
+    let mut snapshot = WalletSnapshot { updated_at: credentials::unix_now(), ..Default::default() };
+    let mut any = false;
+
+    if let Ok(loot) = client.player_loot() {
+        any = true;
+        let (mut blue, mut orange, mut riot_points, mut mythic, mut keys, mut chests) = (0, 0, 0, 0, 0, 0);
+        for item in loot {
+            let count = item.count.max(0) as u64;
+            match (item.loot_id.as_str(), item.loot_type.as_str()) {
+                ("CURRENCY_champion", _) => blue += count,
+                ("CURRENCY_cosmetic", _) => orange += count,
+                ("CURRENCY_RP", _) => riot_points += count,
+                ("CURRENCY_mythic", _) => mythic += count,
+                ("MATERIAL_key", _) => keys += count as u32,
+                (_, "CHEST") => chests += count as u32,
+                _ => {}
+            }
+        }
+        snapshot.blue_essence = Some(blue);
+        snapshot.orange_essence = Some(orange);
+        snapshot.riot_points = Some(riot_points);
+        snapshot.mythic_essence = Some(mythic);
+        snapshot.keys = Some(keys);
+        snapshot.unopened_loot = Some(chests);
+    }
+
+    if let Ok(champions) = client.owned_champions() {
+        any = true;
+        snapshot.champions_owned = Some(
+            champions
+                .iter()
+                .filter(|champion| champion.id > 0 && champion.ownership.owned)
+                .count() as u32,
+        );
+    }
+
+    if let Ok(honor) = client.honor_profile() {
+        any = true;
+        snapshot.honor_level = Some(honor.honor_level);
+    }
+
+    any.then_some(snapshot)
+}
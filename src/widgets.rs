@@ -0,0 +1,49 @@
+use eframe::egui;
+use crate::theme::Palette;
+
+// An on/off switch, sized like a checkbox row and colored from the active theme.
+pub fn toggle_switch(on: &mut bool) -> impl egui::Widget + '_ {
+    move |ui: &mut egui::Ui| {
+        let switch_height = ui.spacing().interact_size.y.max(20.0);
+        let switch_width = switch_height * 2.0;
+        let (rect, mut response) = ui.allocate_exact_size(egui::vec2(switch_width, switch_height), egui::Sense::click());
+        if response.clicked() {
+            *on = !*on;
+            response.mark_changed();
+        }
+        response.widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, ui.is_enabled(), *on, ""));
+
+        if ui.is_rect_visible(rect) {
+            let how_on = ui.ctx().animate_bool_with_time(response.id, *on, 0.2);
+            let palette = Palette::of(ui.visuals());
+            let corner_radius = switch_height / 2.0;
+            let bg_color = palette.switch_off.lerp_to_gamma(palette.accent, how_on);
+            ui.painter().rect_filled(rect, corner_radius, bg_color);
+
+            let circle_radius = corner_radius - 2.0;
+            let circle_x = rect.left() + circle_radius + 2.0 + how_on * (switch_width - switch_height);
+            ui.painter().circle_filled(egui::pos2(circle_x, rect.center().y), circle_radius, palette.knob);
+        }
+
+        response
+    }
+}
+
+// A centered label followed by a switch. Returns true when the value was toggled.
+pub fn toggle_row(ui: &mut egui::Ui, label_text: &str, value: &mut bool) -> bool {
+    ui.horizontal(|ui| {
+        let label_width = ui.painter().layout_no_wrap(
+            label_text.to_string(),
+            egui::TextStyle::Body.resolve(ui.style()),
+            ui.visuals().text_color(),
+        ).size().x;
+        let switch_width = ui.spacing().interact_size.y.max(20.0) * 2.0;
+        let total_w = label_width + 10.0 + switch_width;
+        ui.add_space(((ui.available_width() - total_w) / 2.0).max(0.0));
+
+        ui.label(label_text);
+        ui.add_space(10.0);
+        ui.add(toggle_switch(value)).changed()
+    })
+    .inner
+}